@@ -107,7 +107,8 @@ fn impl_frc_struct(name: &Ident, fields: &Fields) -> TokenStream2 {
 
     //generate schema
     let schema = {
-        let schema_template = "{}".repeat(field_types.len());
+        // fields are separated by `;` as required by the wpilib struct spec
+        let schema_template = vec!["{}"; field_types.len()].join(";");
         let format_exprs = {
             field_types
                 .iter()
@@ -324,3 +325,198 @@ fn impl_frc_enum(
         }
     }
 }
+
+/// Derive macro generating impls of `From<T> for FrcValue`, `TryFrom<FrcValue> for T`
+/// and `StaticallyFrcTyped`.
+///
+/// Supported items:
+/// - C-like enums, represented as a `String` of the variant name by default
+///   or as an `Int` of the discriminant with `#[IntoFrcValue(int)]`
+/// - Newtype structs (a single field), represented the same way as their inner type
+#[proc_macro_derive(IntoFrcValue, attributes(IntoFrcValue))]
+pub fn into_frc_value(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    if !ast.generics.params.is_empty() {
+        return syn::Error::new_spanned(&ast.generics, "IntoFrcValue does not support generics")
+            .to_compile_error()
+            .into();
+    }
+
+    let result = match &ast.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => impl_value_newtype(&ast.ident, fields),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => get_value_enum_repr(&ast.attrs)
+            .and_then(|repr| impl_value_enum(&ast.ident, variants.iter().collect(), repr)),
+        syn::Data::Union(_) => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "IntoFrcValue only supports newtype structs and c-style enums",
+        )),
+    };
+
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueEnumRepr {
+    String,
+    Int,
+}
+
+fn get_value_enum_repr(attrs: &[Attribute]) -> syn::Result<ValueEnumRepr> {
+    let mut repr = ValueEnumRepr::String;
+    for attr in attrs {
+        if !attr.path().is_ident("IntoFrcValue") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("string") {
+                repr = ValueEnumRepr::String;
+                Ok(())
+            } else if meta.path.is_ident("int") {
+                repr = ValueEnumRepr::Int;
+                Ok(())
+            } else {
+                Err(meta.error("expected `string` or `int`"))
+            }
+        })?;
+    }
+    Ok(repr)
+}
+
+fn impl_value_newtype(name: &Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut iter = fields.iter();
+    let (Some(field), None) = (iter.next(), iter.next()) else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "IntoFrcValue can only be derived on structs with exactly one field",
+        ));
+    };
+    let inner = &field.ty;
+    let accessor = match &field.ident {
+        Some(ident) => quote! { #ident },
+        None => quote! { 0 },
+    };
+    let construct = match &field.ident {
+        Some(ident) => quote! { |inner| Self { #ident: inner } },
+        None => quote! { Self },
+    };
+
+    Ok(quote! {
+        impl From<#name> for frclib_core::value::FrcValue {
+            fn from(value: #name) -> Self {
+                frclib_core::value::FrcValue::from(value.#accessor)
+            }
+        }
+        impl TryFrom<frclib_core::value::FrcValue> for #name {
+            type Error = frclib_core::value::FrcValueCastError;
+            fn try_from(value: frclib_core::value::FrcValue) -> Result<Self, Self::Error> {
+                <#inner as TryFrom<frclib_core::value::FrcValue>>::try_from(value)
+                    .map(#construct)
+                    .map_err(|err| match err {
+                        frclib_core::value::FrcValueCastError::InvalidCastTo(from, _, reason) => {
+                            frclib_core::value::FrcValueCastError::InvalidCastTo(
+                                from,
+                                stringify!(#name),
+                                reason,
+                            )
+                        }
+                        other => other,
+                    })
+            }
+        }
+        impl frclib_core::value::StaticallyFrcTyped for #name {
+            const TYPE: frclib_core::value::FrcType =
+                <#inner as frclib_core::value::StaticallyFrcTyped>::TYPE;
+            const COULD_BE_VOID: bool =
+                <#inner as frclib_core::value::StaticallyFrcTyped>::COULD_BE_VOID;
+        }
+    })
+}
+
+fn impl_value_enum(
+    name: &Ident,
+    variants: Vec<&Variant>,
+    repr: ValueEnumRepr,
+) -> syn::Result<TokenStream2> {
+    let mut variant_idents = Vec::with_capacity(variants.len());
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "IntoFrcValue can only be derived on enums without variant fields",
+            ));
+        }
+        variant_idents.push(&variant.ident);
+    }
+    let variant_strs = variant_idents
+        .iter()
+        .map(|ident| syn::LitStr::new(ident.to_string().as_str(), ident.span()))
+        .collect::<Vec<_>>();
+
+    let cast_error = quote! {
+        frclib_core::value::FrcValueCastError::InvalidCastTo(
+            value.get_type(),
+            stringify!(#name),
+            frclib_core::value::CastErrorReason::Deserialization,
+        )
+    };
+    let type_error = quote! {
+        frclib_core::value::FrcValueCastError::InvalidCastTo(
+            value.get_type(),
+            stringify!(#name),
+            frclib_core::value::CastErrorReason::Type,
+        )
+    };
+
+    let (frc_type, into_value, try_from_value) = match repr {
+        ValueEnumRepr::String => (
+            quote! { String },
+            quote! {
+                match value {
+                    #(#name::#variant_idents => frclib_core::value::FrcValue::String(Box::from(#variant_strs)),)*
+                }
+            },
+            quote! {
+                match &value {
+                    frclib_core::value::FrcValue::String(string) => match string.as_ref() {
+                        #(#variant_strs => Ok(Self::#variant_idents),)*
+                        _ => Err(#cast_error),
+                    },
+                    _ => Err(#type_error),
+                }
+            },
+        ),
+        ValueEnumRepr::Int => (
+            quote! { Int },
+            quote! {
+                frclib_core::value::FrcValue::Int(value as i64)
+            },
+            quote! {
+                match &value {
+                    frclib_core::value::FrcValue::Int(int) => match *int {
+                        #(int if int == Self::#variant_idents as i64 => Ok(Self::#variant_idents),)*
+                        _ => Err(#cast_error),
+                    },
+                    _ => Err(#type_error),
+                }
+            },
+        ),
+    };
+
+    Ok(quote! {
+        impl From<#name> for frclib_core::value::FrcValue {
+            fn from(value: #name) -> Self {
+                #into_value
+            }
+        }
+        impl TryFrom<frclib_core::value::FrcValue> for #name {
+            type Error = frclib_core::value::FrcValueCastError;
+            fn try_from(value: frclib_core::value::FrcValue) -> Result<Self, Self::Error> {
+                #try_from_value
+            }
+        }
+        impl frclib_core::value::StaticallyFrcTyped for #name {
+            const TYPE: frclib_core::value::FrcType = frclib_core::value::FrcType::#frc_type;
+        }
+    })
+}
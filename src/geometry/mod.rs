@@ -0,0 +1,66 @@
+//! Unit-typed 2D and 3D geometry built on [`nalgebra`].
+//!
+//! Translations, poses, transforms and twists are generic over the [`Distance`] unit
+//! they are measured in and default to [`Meter`](crate::units::length::Meter), rotations always store radians internally
+//! but hand out any [`Angle`](crate::units::angle::Angle) unit.
+//!
+//! # Example
+//! ```
+//! use frclib_core::geometry::{Pose2d, Rotation2d, Transform2d, Translation2d};
+//! use frclib_core::units::angle::Degree;
+//! use frclib_core::units::length::{Foot, Meter};
+//!
+//! let pose: Pose2d = Pose2d::new(
+//!     Translation2d::new(Meter(1.0), Foot(0.0)),
+//!     Rotation2d::new(Degree(90.0)),
+//! );
+//! let moved = pose + Transform2d::new(Translation2d::new(Meter(2.0), Meter(0.0)), Rotation2d::ZERO);
+//! assert!((moved.y() - Meter(2.0)).0.abs() < 1e-9);
+//! ```
+
+use nalgebra::{RealField, SVector};
+
+use crate::units::length::Distance;
+
+mod pose;
+mod rotation;
+#[cfg(test)]
+mod test;
+mod transform;
+mod translation;
+mod twist;
+
+pub use pose::{Pose2d, Pose3d};
+pub use rotation::{Rotation2d, Rotation3d};
+pub use transform::{Transform2d, Transform3d};
+pub use translation::{Translation2d, Translation3d};
+pub use twist::{Twist2d, Twist3d};
+
+/// A [`Distance`] unit that can be the scalar of the geometry types.
+///
+/// This is implemented for every `float` distance unit.
+pub trait GeometryDistance: Distance + RealField + Default + From<f64> + Into<f64> {}
+impl<T> GeometryDistance for T where T: Distance + RealField + Default + From<f64> + Into<f64> {}
+
+/// Types that can be interpolated between a start and end value.
+pub trait Interpolate: Sized {
+    /// Returns the value `t` of the way from `self` to `end`,
+    /// `t` is clamped to the range `[0, 1]`.
+    #[must_use]
+    fn interpolate(self, end: Self, t: f64) -> Self;
+}
+
+/// Below this angle in radians the twist maps use their taylor expansions.
+const SMALL_ANGLE: f64 = 1e-9;
+
+fn vector_to_f64<D: GeometryDistance, const N: usize>(vector: SVector<D, N>) -> SVector<f64, N> {
+    vector.map(Into::into)
+}
+
+fn vector_from_f64<D: GeometryDistance, const N: usize>(vector: SVector<f64, N>) -> SVector<D, N> {
+    vector.map(<D as From<f64>>::from)
+}
+
+fn scale<D: GeometryDistance>(distance: D, factor: f64) -> D {
+    <D as From<f64>>::from(Into::<f64>::into(distance) * factor)
+}
@@ -0,0 +1,288 @@
+use std::ops::{Add, Sub};
+
+use nalgebra::{Matrix3, Vector2, Vector3};
+
+use super::{
+    vector_from_f64, vector_to_f64, GeometryDistance, Interpolate, Rotation2d, Rotation3d,
+    Transform2d, Transform3d, Translation2d, Translation3d, Twist2d, Twist3d, SMALL_ANGLE,
+};
+use crate::units::{angle::Radian, length::Meter};
+
+/// A position and heading in the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pose2d<D: GeometryDistance = Meter> {
+    translation: Translation2d<D>,
+    rotation: Rotation2d,
+}
+
+impl<D: GeometryDistance> Pose2d<D> {
+    /// Creates a pose from its translation and rotation.
+    #[must_use]
+    pub const fn new(translation: Translation2d<D>, rotation: Rotation2d) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// The position of this pose.
+    #[must_use]
+    pub const fn translation(&self) -> Translation2d<D> {
+        self.translation
+    }
+
+    /// The heading of this pose.
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation2d {
+        self.rotation
+    }
+
+    /// The x component of the position.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.translation.x()
+    }
+
+    /// The y component of the position.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.translation.y()
+    }
+
+    /// Applies `transform` in the frame of this pose.
+    #[must_use]
+    pub fn transform_by(&self, transform: &Transform2d<D>) -> Self {
+        Self::new(
+            self.translation + transform.translation().rotate_by(&self.rotation),
+            self.rotation + transform.rotation(),
+        )
+    }
+
+    /// This pose expressed in the frame of `other`.
+    #[must_use]
+    pub fn relative_to(&self, other: &Self) -> Self {
+        let transform = Transform2d::between(other, self);
+        Self::new(transform.translation(), transform.rotation())
+    }
+
+    /// Follows `twist` from this pose along a constant curvature arc.
+    #[must_use]
+    pub fn exp(&self, twist: &Twist2d<D>) -> Self {
+        let dx: f64 = twist.dx.into();
+        let dy: f64 = twist.dy.into();
+        let dtheta = twist.dtheta.value();
+        let (sin, cos) = dtheta.sin_cos();
+
+        let (s, c) = if dtheta.abs() < SMALL_ANGLE {
+            (1.0 - dtheta * dtheta / 6.0, 0.5 * dtheta)
+        } else {
+            (sin / dtheta, (1.0 - cos) / dtheta)
+        };
+        let translation = Vector2::new(dx.mul_add(s, -dy * c), dx.mul_add(c, dy * s));
+
+        self.transform_by(&Transform2d::new(
+            Translation2d::from(vector_from_f64(translation)),
+            Rotation2d::from_components(cos, sin),
+        ))
+    }
+
+    /// The twist that takes this pose to `end` along a constant curvature arc,
+    /// this is the inverse of [`exp`](Self::exp).
+    #[must_use]
+    pub fn log(&self, end: &Self) -> Twist2d<D> {
+        let transform = Transform2d::between(self, end);
+        let dtheta = transform.rotation().radians().value();
+        let half_dtheta = dtheta / 2.0;
+        let cos_minus_one = transform.rotation().cos() - 1.0;
+
+        let half_theta_by_tan = if cos_minus_one.abs() < SMALL_ANGLE {
+            1.0 - dtheta * dtheta / 12.0
+        } else {
+            -(half_dtheta * transform.rotation().sin()) / cos_minus_one
+        };
+        let translation = transform
+            .translation()
+            .rotate_by(&Rotation2d::from_components(
+                half_theta_by_tan,
+                -half_dtheta,
+            ))
+            * half_theta_by_tan.hypot(half_dtheta);
+
+        Twist2d::new(translation.x(), translation.y(), Radian(dtheta))
+    }
+}
+
+impl<D: GeometryDistance> Add<Transform2d<D>> for Pose2d<D> {
+    type Output = Self;
+    fn add(self, rhs: Transform2d<D>) -> Self::Output {
+        self.transform_by(&rhs)
+    }
+}
+
+impl<D: GeometryDistance> Sub for Pose2d<D> {
+    type Output = Transform2d<D>;
+    /// The transform that takes `rhs` to `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Transform2d::between(&rhs, &self)
+    }
+}
+
+impl<D: GeometryDistance> Interpolate for Pose2d<D> {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self.exp(&(self.log(&end) * t.clamp(0.0, 1.0)))
+    }
+}
+
+/// A position and orientation in space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pose3d<D: GeometryDistance = Meter> {
+    translation: Translation3d<D>,
+    rotation: Rotation3d,
+}
+
+impl<D: GeometryDistance> Pose3d<D> {
+    /// Creates a pose from its translation and rotation.
+    #[must_use]
+    pub const fn new(translation: Translation3d<D>, rotation: Rotation3d) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// The position of this pose.
+    #[must_use]
+    pub const fn translation(&self) -> Translation3d<D> {
+        self.translation
+    }
+
+    /// The orientation of this pose.
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation3d {
+        self.rotation
+    }
+
+    /// The x component of the position.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.translation.x()
+    }
+
+    /// The y component of the position.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.translation.y()
+    }
+
+    /// The z component of the position.
+    #[must_use]
+    pub fn z(&self) -> D {
+        self.translation.z()
+    }
+
+    /// Applies `transform` in the frame of this pose.
+    #[must_use]
+    pub fn transform_by(&self, transform: &Transform3d<D>) -> Self {
+        Self::new(
+            self.translation + transform.translation().rotate_by(&self.rotation),
+            transform.rotation().rotate_by(&self.rotation),
+        )
+    }
+
+    /// This pose expressed in the frame of `other`.
+    #[must_use]
+    pub fn relative_to(&self, other: &Self) -> Self {
+        let transform = Transform3d::between(other, self);
+        Self::new(transform.translation(), transform.rotation())
+    }
+
+    /// The projection of this pose onto the xy plane, keeping only the yaw.
+    #[must_use]
+    pub fn to_pose2d(&self) -> Pose2d<D> {
+        Pose2d::new(
+            self.translation.to_translation2d(),
+            self.rotation.to_rotation2d(),
+        )
+    }
+
+    /// Follows `twist` from this pose along a constant screw motion.
+    #[must_use]
+    pub fn exp(&self, twist: &Twist3d<D>) -> Self {
+        let u = vector_to_f64(Vector3::new(twist.dx, twist.dy, twist.dz));
+        let omega = Vector3::new(twist.rx.value(), twist.ry.value(), twist.rz.value());
+        let omega_hat = omega.cross_matrix();
+        let theta_sq = omega.norm_squared();
+        let theta = theta_sq.sqrt();
+
+        let (b, c) = if theta < SMALL_ANGLE {
+            (0.5 - theta_sq / 24.0, 1.0 / 6.0 - theta_sq / 120.0)
+        } else {
+            let a = theta.sin() / theta;
+            ((1.0 - theta.cos()) / theta_sq, (1.0 - a) / theta_sq)
+        };
+        let v = Matrix3::identity() + omega_hat * b + omega_hat * omega_hat * c;
+
+        self.transform_by(&Transform3d::new(
+            Translation3d::from(vector_from_f64(v * u)),
+            Rotation3d::from_rotation_vector(omega),
+        ))
+    }
+
+    /// The twist that takes this pose to `end` along a constant screw motion,
+    /// this is the inverse of [`exp`](Self::exp).
+    #[must_use]
+    pub fn log(&self, end: &Self) -> Twist3d<D> {
+        let transform = Transform3d::between(self, end);
+        let omega = transform.rotation().rotation_vector();
+        let omega_hat = omega.cross_matrix();
+        let theta_sq = omega.norm_squared();
+        let theta = theta_sq.sqrt();
+
+        let c = if theta < SMALL_ANGLE {
+            1.0 / 12.0 + theta_sq / 720.0
+        } else {
+            let a = theta.sin() / theta;
+            let b = (1.0 - theta.cos()) / theta_sq;
+            (1.0 - a / (2.0 * b)) / theta_sq
+        };
+        let v_inv = Matrix3::identity() - omega_hat * 0.5 + omega_hat * omega_hat * c;
+        let u: Vector3<D> =
+            vector_from_f64(v_inv * vector_to_f64(*transform.translation().as_vector()));
+
+        Twist3d::new(
+            u.x,
+            u.y,
+            u.z,
+            Radian(omega.x),
+            Radian(omega.y),
+            Radian(omega.z),
+        )
+    }
+}
+
+impl<D: GeometryDistance> Add<Transform3d<D>> for Pose3d<D> {
+    type Output = Self;
+    fn add(self, rhs: Transform3d<D>) -> Self::Output {
+        self.transform_by(&rhs)
+    }
+}
+
+impl<D: GeometryDistance> Sub for Pose3d<D> {
+    type Output = Transform3d<D>;
+    /// The transform that takes `rhs` to `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Transform3d::between(&rhs, &self)
+    }
+}
+
+impl<D: GeometryDistance> From<Pose2d<D>> for Pose3d<D> {
+    fn from(pose: Pose2d<D>) -> Self {
+        Self::new(pose.translation().into(), pose.rotation().into())
+    }
+}
+
+impl<D: GeometryDistance> Interpolate for Pose3d<D> {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self.exp(&(self.log(&end) * t.clamp(0.0, 1.0)))
+    }
+}
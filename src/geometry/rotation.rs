@@ -0,0 +1,323 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use nalgebra::{Complex, UnitComplex, UnitQuaternion, Vector3};
+
+use super::Interpolate;
+use crate::units::angle::{Angle, Degree, Radian, Rotation};
+
+/// A rotation in the plane, counter-clockwise positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation2d {
+    rotation: UnitComplex<f64>,
+}
+
+impl Rotation2d {
+    /// A rotation of zero radians.
+    pub const ZERO: Self = Self {
+        rotation: UnitComplex::new_unchecked(Complex { re: 1.0, im: 0.0 }),
+    };
+
+    /// Creates a rotation of the given angle.
+    #[must_use]
+    pub fn new(angle: impl Angle) -> Self {
+        Self {
+            rotation: UnitComplex::new(angle.standard().value()),
+        }
+    }
+
+    /// Creates the rotation pointing along the vector `(x, y)`.
+    ///
+    /// A zero vector gives [`Rotation2d::ZERO`].
+    #[must_use]
+    pub fn from_components(x: f64, y: f64) -> Self {
+        let norm = x.hypot(y);
+        if norm > f64::EPSILON {
+            Self {
+                rotation: UnitComplex::from_cos_sin_unchecked(x / norm, y / norm),
+            }
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// The angle of this rotation in the range `(-π, π]`.
+    #[must_use]
+    pub fn radians(&self) -> Radian {
+        Radian(self.rotation.angle())
+    }
+
+    /// The angle of this rotation in the range `(-180, 180]`.
+    #[must_use]
+    pub fn degrees(&self) -> Degree {
+        self.radians().into()
+    }
+
+    /// The angle of this rotation in the range `(-0.5, 0.5]`.
+    #[must_use]
+    pub fn rotations(&self) -> Rotation {
+        self.radians().into()
+    }
+
+    /// The cosine of this rotation.
+    #[must_use]
+    pub fn cos(&self) -> f64 {
+        self.rotation.cos_angle()
+    }
+
+    /// The sine of this rotation.
+    #[must_use]
+    pub fn sin(&self) -> f64 {
+        self.rotation.sin_angle()
+    }
+
+    /// The tangent of this rotation.
+    #[must_use]
+    pub fn tan(&self) -> f64 {
+        self.sin() / self.cos()
+    }
+
+    /// Applies `other` on top of this rotation.
+    #[must_use]
+    pub fn rotate_by(&self, other: &Self) -> Self {
+        Self {
+            rotation: other.rotation * self.rotation,
+        }
+    }
+
+    /// The rotation that undoes this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self {
+            rotation: self.rotation.inverse(),
+        }
+    }
+
+    /// The underlying [`nalgebra`] rotation.
+    #[must_use]
+    pub const fn as_unit_complex(&self) -> &UnitComplex<f64> {
+        &self.rotation
+    }
+}
+
+impl Default for Rotation2d {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<UnitComplex<f64>> for Rotation2d {
+    fn from(rotation: UnitComplex<f64>) -> Self {
+        Self { rotation }
+    }
+}
+
+impl From<Rotation2d> for UnitComplex<f64> {
+    fn from(rotation: Rotation2d) -> Self {
+        rotation.rotation
+    }
+}
+
+impl Add for Rotation2d {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.rotate_by(&rhs)
+    }
+}
+
+impl Sub for Rotation2d {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.rotate_by(&rhs.inverse())
+    }
+}
+
+impl Neg for Rotation2d {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl Mul<f64> for Rotation2d {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.radians() * rhs)
+    }
+}
+
+impl Interpolate for Rotation2d {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self + (end - self) * t.clamp(0.0, 1.0)
+    }
+}
+
+/// A rotation in space, stored as a unit quaternion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation3d {
+    rotation: UnitQuaternion<f64>,
+}
+
+impl Rotation3d {
+    /// Creates a rotation from extrinsic roll, pitch and yaw angles,
+    /// applied about the x, y and z axes in that order.
+    #[must_use]
+    pub fn new(roll: impl Angle, pitch: impl Angle, yaw: impl Angle) -> Self {
+        Self {
+            rotation: UnitQuaternion::from_euler_angles(
+                roll.standard().value(),
+                pitch.standard().value(),
+                yaw.standard().value(),
+            ),
+        }
+    }
+
+    /// Creates a rotation of `angle` about `axis`, a zero axis gives no rotation.
+    #[must_use]
+    pub fn from_axis_angle(axis: Vector3<f64>, angle: impl Angle) -> Self {
+        let norm = axis.norm();
+        if norm > f64::EPSILON {
+            Self::from_rotation_vector(axis * (angle.standard().value() / norm))
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Creates a rotation from a rotation vector,
+    /// the direction is the axis and the length is the angle in radians.
+    #[must_use]
+    pub fn from_rotation_vector(rotation_vector: Vector3<f64>) -> Self {
+        Self {
+            rotation: UnitQuaternion::from_scaled_axis(rotation_vector),
+        }
+    }
+
+    /// The counter-clockwise rotation about the x axis.
+    #[must_use]
+    pub fn roll(&self) -> Radian {
+        Radian(self.rotation.euler_angles().0)
+    }
+
+    /// The counter-clockwise rotation about the y axis.
+    #[must_use]
+    pub fn pitch(&self) -> Radian {
+        Radian(self.rotation.euler_angles().1)
+    }
+
+    /// The counter-clockwise rotation about the z axis.
+    #[must_use]
+    pub fn yaw(&self) -> Radian {
+        Radian(self.rotation.euler_angles().2)
+    }
+
+    /// The angle rotated about [`axis`](Self::axis).
+    #[must_use]
+    pub fn angle(&self) -> Radian {
+        Radian(self.rotation.angle())
+    }
+
+    /// The unit axis of rotation, this is zero when there is no rotation.
+    #[must_use]
+    pub fn axis(&self) -> Vector3<f64> {
+        self.rotation
+            .axis()
+            .map_or_else(Vector3::zeros, nalgebra::Unit::into_inner)
+    }
+
+    /// The axis scaled by the angle in radians.
+    #[must_use]
+    pub fn rotation_vector(&self) -> Vector3<f64> {
+        self.rotation.scaled_axis()
+    }
+
+    /// Applies `other` on top of this rotation.
+    #[must_use]
+    pub fn rotate_by(&self, other: &Self) -> Self {
+        Self {
+            rotation: other.rotation * self.rotation,
+        }
+    }
+
+    /// The rotation that undoes this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self {
+            rotation: self.rotation.inverse(),
+        }
+    }
+
+    /// The yaw of this rotation as a planar rotation.
+    #[must_use]
+    pub fn to_rotation2d(&self) -> Rotation2d {
+        Rotation2d::new(self.yaw())
+    }
+
+    /// The underlying [`nalgebra`] rotation.
+    #[must_use]
+    pub const fn as_unit_quaternion(&self) -> &UnitQuaternion<f64> {
+        &self.rotation
+    }
+}
+
+impl Default for Rotation3d {
+    fn default() -> Self {
+        Self {
+            rotation: UnitQuaternion::identity(),
+        }
+    }
+}
+
+impl From<UnitQuaternion<f64>> for Rotation3d {
+    fn from(rotation: UnitQuaternion<f64>) -> Self {
+        Self { rotation }
+    }
+}
+
+impl From<Rotation3d> for UnitQuaternion<f64> {
+    fn from(rotation: Rotation3d) -> Self {
+        rotation.rotation
+    }
+}
+
+impl From<Rotation2d> for Rotation3d {
+    fn from(rotation: Rotation2d) -> Self {
+        Self::new(Radian(0.0), Radian(0.0), rotation.radians())
+    }
+}
+
+impl Add for Rotation3d {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.rotate_by(&rhs)
+    }
+}
+
+impl Sub for Rotation3d {
+    type Output = Self;
+    /// The rotation that takes `rhs` to `self`, so `rhs + (self - rhs) == self`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            rotation: self.rotation * rhs.rotation.inverse(),
+        }
+    }
+}
+
+impl Neg for Rotation3d {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl Mul<f64> for Rotation3d {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_rotation_vector(self.rotation_vector() * rhs)
+    }
+}
+
+impl Interpolate for Rotation3d {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self + (end - self) * t.clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,209 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::geometry::{
+    Interpolate, Pose2d, Pose3d, Rotation2d, Rotation3d, Transform2d, Transform3d, Translation2d,
+    Translation3d, Twist2d, Twist3d,
+};
+use crate::units::{
+    angle::{Degree, Radian},
+    length::{Foot, Inch, Meter},
+};
+
+fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>) {
+    let (actual, expected) = (actual.into(), expected.into());
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+fn assert_pose2d_close(actual: &Pose2d, expected: &Pose2d) {
+    assert_close(actual.x(), expected.x());
+    assert_close(actual.y(), expected.y());
+    assert_close(
+        (actual.rotation() - expected.rotation()).radians(),
+        Radian(0.0),
+    );
+}
+
+fn assert_pose3d_close(actual: &Pose3d, expected: &Pose3d) {
+    assert_close(
+        actual.translation().distance(&expected.translation()),
+        Meter(0.0),
+    );
+    assert_close(
+        (actual.rotation() - expected.rotation()).angle(),
+        Radian(0.0),
+    );
+}
+
+#[test]
+fn translation() {
+    let translation: Translation2d = Translation2d::new(Foot(10.0), Inch(0.0));
+    assert_close(translation.x(), Meter(3.048));
+    assert_eq!(translation.conv::<Foot>().x(), Foot(10.0));
+
+    let rotated = translation.rotate_by(&Rotation2d::new(Degree(90.0)));
+    assert_close(rotated.x(), Meter(0.0));
+    assert_close(rotated.y(), Meter(3.048));
+    assert_close(rotated.angle().degrees(), Degree(90.0));
+
+    let translation = Translation2d::<Meter>::new(Meter(3.0), Meter(4.0));
+    assert_eq!(translation.norm(), Meter(5.0));
+    assert_eq!(translation.distance(&Translation2d::default()), Meter(5.0));
+    assert_eq!(
+        translation.interpolate(Translation2d::default(), 0.5),
+        Translation2d::new(Meter(1.5), Meter(2.0))
+    );
+
+    let translation = Translation3d::<Meter>::new(Meter(1.0), Meter(0.0), Meter(0.0));
+    let rotated = translation.rotate_by(&Rotation3d::new(Degree(0.0), Degree(0.0), Degree(90.0)));
+    assert_close(rotated.x(), Meter(0.0));
+    assert_close(rotated.y(), Meter(1.0));
+    assert_close(rotated.z(), Meter(0.0));
+}
+
+#[test]
+fn rotation() {
+    let rotation = Rotation2d::new(Degree(270.0));
+    assert_close(rotation.degrees(), Degree(-90.0));
+    assert_close(
+        (rotation + Rotation2d::new(Degree(180.0))).degrees(),
+        Degree(90.0),
+    );
+    assert_close(
+        Rotation2d::new(Degree(10.0))
+            .interpolate(Rotation2d::new(Degree(-150.0)), 0.5)
+            .degrees(),
+        Degree(-70.0),
+    );
+
+    let rotation = Rotation3d::new(Degree(10.0), Degree(20.0), Degree(30.0));
+    assert_close(Degree::from(rotation.roll()), Degree(10.0));
+    assert_close(Degree::from(rotation.pitch()), Degree(20.0));
+    assert_close(Degree::from(rotation.yaw()), Degree(30.0));
+    assert_close(rotation.to_rotation2d().degrees(), Degree(30.0));
+
+    let other = Rotation3d::from_axis_angle(nalgebra::Vector3::x(), Degree(45.0));
+    let difference = rotation - other;
+    assert_close((other + difference - rotation).angle(), Radian(0.0));
+    assert_close(
+        (other.interpolate(rotation, 1.0) - rotation).angle(),
+        Radian(0.0),
+    );
+    assert_close(
+        (other.interpolate(rotation, 0.5) - other).angle(),
+        (rotation - other).angle() / 2.0,
+    );
+}
+
+#[test]
+fn transforms() {
+    let pose: Pose2d = Pose2d::new(
+        Translation2d::new(Meter(1.0), Meter(2.0)),
+        Rotation2d::new(Degree(90.0)),
+    );
+    let transform = Transform2d::new(
+        Translation2d::new(Meter(1.0), Meter(0.0)),
+        Rotation2d::new(Degree(45.0)),
+    );
+    let moved = pose + transform;
+    assert_pose2d_close(
+        &moved,
+        &Pose2d::new(
+            Translation2d::new(Meter(1.0), Meter(3.0)),
+            Rotation2d::new(Degree(135.0)),
+        ),
+    );
+    assert_pose2d_close(&(moved + transform.inverse()), &pose);
+    assert_eq!(moved - pose, Transform2d::between(&pose, &moved));
+    assert_pose2d_close(&(pose + (moved - pose)), &moved);
+    assert_pose2d_close(
+        &(pose + (transform + transform)),
+        &(pose + transform + transform),
+    );
+    assert_pose2d_close(
+        &moved.relative_to(&pose),
+        &Pose2d::new(transform.translation(), transform.rotation()),
+    );
+
+    let pose: Pose3d = Pose3d::new(
+        Translation3d::new(Meter(1.0), Meter(2.0), Meter(3.0)),
+        Rotation3d::new(Degree(30.0), Degree(-20.0), Degree(90.0)),
+    );
+    let transform = Transform3d::new(
+        Translation3d::new(Meter(1.0), Meter(-1.0), Meter(0.5)),
+        Rotation3d::new(Degree(5.0), Degree(15.0), Degree(45.0)),
+    );
+    let moved = pose + transform;
+    assert_pose3d_close(&(moved + transform.inverse()), &pose);
+    assert_pose3d_close(&(pose + (moved - pose)), &moved);
+    assert_pose3d_close(
+        &(pose + (transform + transform)),
+        &(pose + transform + transform),
+    );
+}
+
+#[test]
+fn twists() {
+    let quarter_circle = Twist2d::new(Meter(FRAC_PI_2), Meter(0.0), Radian(FRAC_PI_2));
+    let end = Pose2d::default().exp(&quarter_circle);
+    assert_pose2d_close(
+        &end,
+        &Pose2d::new(
+            Translation2d::new(Meter(1.0), Meter(1.0)),
+            Rotation2d::new(Degree(90.0)),
+        ),
+    );
+    let twist = Pose2d::default().log(&end);
+    assert_close(twist.dx, quarter_circle.dx);
+    assert_close(twist.dy, quarter_circle.dy);
+    assert_close(twist.dtheta, quarter_circle.dtheta);
+    let halfway = std::f64::consts::FRAC_PI_4;
+    assert_pose2d_close(
+        &Pose2d::default().interpolate(end, 0.5),
+        &Pose2d::new(
+            Translation2d::new(Meter(halfway.sin()), Meter(1.0 - halfway.cos())),
+            Rotation2d::new(Degree(45.0)),
+        ),
+    );
+
+    let start: Pose2d = Pose2d::new(
+        Translation2d::new(Meter(2.0), Meter(-1.0)),
+        Rotation2d::new(Degree(20.0)),
+    );
+    let end = Pose2d::new(
+        Translation2d::new(Meter(-3.0), Meter(4.0)),
+        Rotation2d::new(Degree(160.0)),
+    );
+    assert_pose2d_close(&start.exp(&start.log(&end)), &end);
+
+    let straight = Twist3d::new(
+        Meter(2.0),
+        Meter(0.0),
+        Meter(0.0),
+        Radian(0.0),
+        Radian(0.0),
+        Radian(0.0),
+    );
+    assert_pose3d_close(
+        &Pose3d::default().exp(&straight),
+        &Pose3d::new(
+            Translation3d::new(Meter(2.0), Meter(0.0), Meter(0.0)),
+            Rotation3d::default(),
+        ),
+    );
+    let start: Pose3d = Pose3d::new(
+        Translation3d::new(Meter(1.0), Meter(2.0), Meter(3.0)),
+        Rotation3d::new(Degree(30.0), Degree(-20.0), Degree(90.0)),
+    );
+    let end = Pose3d::new(
+        Translation3d::new(Meter(-2.0), Meter(0.5), Meter(1.0)),
+        Rotation3d::new(Degree(-60.0), Degree(40.0), Degree(120.0)),
+    );
+    assert_pose3d_close(&start.exp(&start.log(&end)), &end);
+    assert_pose3d_close(&start.interpolate(end, 1.0), &end);
+    assert_pose3d_close(&start.interpolate(end, 0.0), &start);
+    assert_pose2d_close(
+        &Pose3d::from(Pose2d::default().interpolate(Pose2d::default().exp(&quarter_circle), 0.5))
+            .to_pose2d(),
+        &Pose2d::default().exp(&(quarter_circle * 0.5)),
+    );
+}
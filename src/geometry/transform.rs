@@ -0,0 +1,178 @@
+use std::ops::{Add, Mul, Neg};
+
+use super::{
+    GeometryDistance, Pose2d, Pose3d, Rotation2d, Rotation3d, Translation2d, Translation3d,
+};
+use crate::units::length::Meter;
+
+/// A change in position and heading applied in the frame of a [`Pose2d`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Transform2d<D: GeometryDistance = Meter> {
+    translation: Translation2d<D>,
+    rotation: Rotation2d,
+}
+
+impl<D: GeometryDistance> Transform2d<D> {
+    /// Creates a transform from its translation and rotation.
+    #[must_use]
+    pub const fn new(translation: Translation2d<D>, rotation: Rotation2d) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// The transform that takes `initial` to `last`.
+    #[must_use]
+    pub fn between(initial: &Pose2d<D>, last: &Pose2d<D>) -> Self {
+        Self::new(
+            (last.translation() - initial.translation()).rotate_by(&-initial.rotation()),
+            last.rotation() - initial.rotation(),
+        )
+    }
+
+    /// The translation of this transform.
+    #[must_use]
+    pub const fn translation(&self) -> Translation2d<D> {
+        self.translation
+    }
+
+    /// The rotation of this transform.
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation2d {
+        self.rotation
+    }
+
+    /// The x component of the translation.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.translation.x()
+    }
+
+    /// The y component of the translation.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.translation.y()
+    }
+
+    /// The transform that undoes this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self::new(
+            (-self.translation).rotate_by(&-self.rotation),
+            -self.rotation,
+        )
+    }
+}
+
+impl<D: GeometryDistance> Add for Transform2d<D> {
+    type Output = Self;
+    /// Applies `rhs` after `self`.
+    fn add(self, rhs: Self) -> Self::Output {
+        let origin = Pose2d::default();
+        Self::between(&origin, &(origin + self + rhs))
+    }
+}
+
+impl<D: GeometryDistance> Neg for Transform2d<D> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Transform2d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.translation * rhs, self.rotation * rhs)
+    }
+}
+
+/// A change in position and orientation applied in the frame of a [`Pose3d`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Transform3d<D: GeometryDistance = Meter> {
+    translation: Translation3d<D>,
+    rotation: Rotation3d,
+}
+
+impl<D: GeometryDistance> Transform3d<D> {
+    /// Creates a transform from its translation and rotation.
+    #[must_use]
+    pub const fn new(translation: Translation3d<D>, rotation: Rotation3d) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// The transform that takes `initial` to `last`.
+    #[must_use]
+    pub fn between(initial: &Pose3d<D>, last: &Pose3d<D>) -> Self {
+        Self::new(
+            (last.translation() - initial.translation()).rotate_by(&-initial.rotation()),
+            last.rotation().rotate_by(&-initial.rotation()),
+        )
+    }
+
+    /// The translation of this transform.
+    #[must_use]
+    pub const fn translation(&self) -> Translation3d<D> {
+        self.translation
+    }
+
+    /// The rotation of this transform.
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation3d {
+        self.rotation
+    }
+
+    /// The x component of the translation.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.translation.x()
+    }
+
+    /// The y component of the translation.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.translation.y()
+    }
+
+    /// The z component of the translation.
+    #[must_use]
+    pub fn z(&self) -> D {
+        self.translation.z()
+    }
+
+    /// The transform that undoes this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self::new(
+            (-self.translation).rotate_by(&-self.rotation),
+            -self.rotation,
+        )
+    }
+}
+
+impl<D: GeometryDistance> Add for Transform3d<D> {
+    type Output = Self;
+    /// Applies `rhs` after `self`.
+    fn add(self, rhs: Self) -> Self::Output {
+        let origin = Pose3d::default();
+        Self::between(&origin, &(origin + self + rhs))
+    }
+}
+
+impl<D: GeometryDistance> Neg for Transform3d<D> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Transform3d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.translation * rhs, self.rotation * rhs)
+    }
+}
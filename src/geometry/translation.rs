@@ -0,0 +1,262 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use nalgebra::{Vector2, Vector3};
+
+use super::{
+    vector_from_f64, vector_to_f64, GeometryDistance, Interpolate, Rotation2d, Rotation3d,
+};
+use crate::units::length::{Distance, Meter};
+
+/// A translation in the plane measured in the distance unit `D`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Translation2d<D: GeometryDistance = Meter> {
+    vector: Vector2<D>,
+}
+
+impl<D: GeometryDistance> Translation2d<D> {
+    /// Creates a translation from its components.
+    #[must_use]
+    pub fn new(x: impl Distance, y: impl Distance) -> Self {
+        Self {
+            vector: Vector2::new(D::from(x.standard()), D::from(y.standard())),
+        }
+    }
+
+    /// Creates a translation `distance` away from the origin in the direction of `angle`.
+    #[must_use]
+    pub fn from_polar(distance: impl Distance, angle: Rotation2d) -> Self {
+        let distance: f64 = D::from(distance.standard()).into();
+        Self::from(vector_from_f64(Vector2::new(
+            distance * angle.cos(),
+            distance * angle.sin(),
+        )))
+    }
+
+    /// The x component of this translation.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.vector.x
+    }
+
+    /// The y component of this translation.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.vector.y
+    }
+
+    /// The distance from the origin to this translation.
+    #[must_use]
+    pub fn norm(&self) -> D {
+        self.vector.norm()
+    }
+
+    /// The distance between this translation and `other`.
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> D {
+        (self.vector - other.vector).norm()
+    }
+
+    /// The direction of this translation from the origin.
+    #[must_use]
+    pub fn angle(&self) -> Rotation2d {
+        Rotation2d::from_components(self.vector.x.into(), self.vector.y.into())
+    }
+
+    /// Rotates this translation counter-clockwise about the origin.
+    #[must_use]
+    pub fn rotate_by(&self, rotation: &Rotation2d) -> Self {
+        Self::from(vector_from_f64(
+            rotation.as_unit_complex() * vector_to_f64(self.vector),
+        ))
+    }
+
+    /// Converts this translation to another distance unit.
+    #[must_use]
+    pub fn conv<U: GeometryDistance>(&self) -> Translation2d<U> {
+        Translation2d::new(self.vector.x, self.vector.y)
+    }
+
+    /// The underlying [`nalgebra`] vector.
+    #[must_use]
+    pub const fn as_vector(&self) -> &Vector2<D> {
+        &self.vector
+    }
+}
+
+impl<D: GeometryDistance> From<Vector2<D>> for Translation2d<D> {
+    fn from(vector: Vector2<D>) -> Self {
+        Self { vector }
+    }
+}
+
+impl<D: GeometryDistance> Add for Translation2d<D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from(self.vector + rhs.vector)
+    }
+}
+
+impl<D: GeometryDistance> Sub for Translation2d<D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from(self.vector - rhs.vector)
+    }
+}
+
+impl<D: GeometryDistance> Neg for Translation2d<D> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from(-self.vector)
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Translation2d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from(vector_from_f64(vector_to_f64(self.vector) * rhs))
+    }
+}
+
+impl<D: GeometryDistance> Div<f64> for Translation2d<D> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::from(vector_from_f64(vector_to_f64(self.vector) / rhs))
+    }
+}
+
+impl<D: GeometryDistance> Interpolate for Translation2d<D> {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self + (end - self) * t.clamp(0.0, 1.0)
+    }
+}
+
+/// A translation in space measured in the distance unit `D`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Translation3d<D: GeometryDistance = Meter> {
+    vector: Vector3<D>,
+}
+
+impl<D: GeometryDistance> Translation3d<D> {
+    /// Creates a translation from its components.
+    #[must_use]
+    pub fn new(x: impl Distance, y: impl Distance, z: impl Distance) -> Self {
+        Self {
+            vector: Vector3::new(
+                D::from(x.standard()),
+                D::from(y.standard()),
+                D::from(z.standard()),
+            ),
+        }
+    }
+
+    /// The x component of this translation.
+    #[must_use]
+    pub fn x(&self) -> D {
+        self.vector.x
+    }
+
+    /// The y component of this translation.
+    #[must_use]
+    pub fn y(&self) -> D {
+        self.vector.y
+    }
+
+    /// The z component of this translation.
+    #[must_use]
+    pub fn z(&self) -> D {
+        self.vector.z
+    }
+
+    /// The distance from the origin to this translation.
+    #[must_use]
+    pub fn norm(&self) -> D {
+        self.vector.norm()
+    }
+
+    /// The distance between this translation and `other`.
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> D {
+        (self.vector - other.vector).norm()
+    }
+
+    /// Rotates this translation about the origin.
+    #[must_use]
+    pub fn rotate_by(&self, rotation: &Rotation3d) -> Self {
+        Self::from(vector_from_f64(
+            rotation.as_unit_quaternion() * vector_to_f64(self.vector),
+        ))
+    }
+
+    /// The projection of this translation onto the xy plane.
+    #[must_use]
+    pub fn to_translation2d(&self) -> Translation2d<D> {
+        Translation2d::from(self.vector.xy())
+    }
+
+    /// Converts this translation to another distance unit.
+    #[must_use]
+    pub fn conv<U: GeometryDistance>(&self) -> Translation3d<U> {
+        Translation3d::new(self.vector.x, self.vector.y, self.vector.z)
+    }
+
+    /// The underlying [`nalgebra`] vector.
+    #[must_use]
+    pub const fn as_vector(&self) -> &Vector3<D> {
+        &self.vector
+    }
+}
+
+impl<D: GeometryDistance> From<Vector3<D>> for Translation3d<D> {
+    fn from(vector: Vector3<D>) -> Self {
+        Self { vector }
+    }
+}
+
+impl<D: GeometryDistance> From<Translation2d<D>> for Translation3d<D> {
+    fn from(translation: Translation2d<D>) -> Self {
+        Self::new(translation.x(), translation.y(), D::from(0.0))
+    }
+}
+
+impl<D: GeometryDistance> Add for Translation3d<D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from(self.vector + rhs.vector)
+    }
+}
+
+impl<D: GeometryDistance> Sub for Translation3d<D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from(self.vector - rhs.vector)
+    }
+}
+
+impl<D: GeometryDistance> Neg for Translation3d<D> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from(-self.vector)
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Translation3d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from(vector_from_f64(vector_to_f64(self.vector) * rhs))
+    }
+}
+
+impl<D: GeometryDistance> Div<f64> for Translation3d<D> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::from(vector_from_f64(vector_to_f64(self.vector) / rhs))
+    }
+}
+
+impl<D: GeometryDistance> Interpolate for Translation3d<D> {
+    fn interpolate(self, end: Self, t: f64) -> Self {
+        self + (end - self) * t.clamp(0.0, 1.0)
+    }
+}
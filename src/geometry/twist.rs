@@ -0,0 +1,78 @@
+use std::ops::Mul;
+
+use super::{scale, GeometryDistance};
+use crate::units::{angle::Radian, length::Meter};
+
+/// A change in a [`Pose2d`](super::Pose2d) along an arc,
+/// measured in the pose's own frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Twist2d<D: GeometryDistance = Meter> {
+    /// The change in x, forward
+    pub dx: D,
+    /// The change in y, left
+    pub dy: D,
+    /// The change in heading, counter-clockwise positive
+    pub dtheta: Radian,
+}
+
+impl<D: GeometryDistance> Twist2d<D> {
+    /// Creates a new twist from its components.
+    #[must_use]
+    pub const fn new(dx: D, dy: D, dtheta: Radian) -> Self {
+        Self { dx, dy, dtheta }
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Twist2d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(scale(self.dx, rhs), scale(self.dy, rhs), self.dtheta * rhs)
+    }
+}
+
+/// A change in a [`Pose3d`](super::Pose3d) along a screw motion,
+/// measured in the pose's own frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Twist3d<D: GeometryDistance = Meter> {
+    /// The change in x, forward
+    pub dx: D,
+    /// The change in y, left
+    pub dy: D,
+    /// The change in z, up
+    pub dz: D,
+    /// The rotation about the x axis
+    pub rx: Radian,
+    /// The rotation about the y axis
+    pub ry: Radian,
+    /// The rotation about the z axis
+    pub rz: Radian,
+}
+
+impl<D: GeometryDistance> Twist3d<D> {
+    /// Creates a new twist from its components.
+    #[must_use]
+    pub const fn new(dx: D, dy: D, dz: D, rx: Radian, ry: Radian, rz: Radian) -> Self {
+        Self {
+            dx,
+            dy,
+            dz,
+            rx,
+            ry,
+            rz,
+        }
+    }
+}
+
+impl<D: GeometryDistance> Mul<f64> for Twist3d<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(
+            scale(self.dx, rhs),
+            scale(self.dy, rhs),
+            scale(self.dz, rhs),
+            self.rx * rhs,
+            self.ry * rhs,
+            self.rz * rhs,
+        )
+    }
+}
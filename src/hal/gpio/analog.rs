@@ -1,6 +1,6 @@
 //! Analog GPIO functionality and traits.
 
-use crate::units::energy::Volt;
+use crate::units::voltage::Volt;
 
 use super::{Channel, GPIOError, GPIOPortType};
 
@@ -45,7 +45,7 @@ pub trait Channel {
 use analog::{AnalogInput, AnalogOutput};
 use digital::{DigitalInput, DigitalOutput};
 
-use crate::units::energy::Volt;
+use crate::units::voltage::Volt;
 
 use super::NotSimError;
 
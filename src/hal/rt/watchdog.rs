@@ -1,6 +1,6 @@
 //! A module for the HAL watchdog driver.
 
-use crate::{hal::NotSimError, units::energy::Volt};
+use crate::{hal::NotSimError, units::voltage::Volt};
 
 /// A trait for the watchdog driver
 pub trait WatchdogDriver: 'static {
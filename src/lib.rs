@@ -42,6 +42,15 @@
 // - [Data](crate::units::data)
 // - [Data Rate](crate::units::data_rate)
 //
+// ### [Geometry](crate::geometry)
+//
+// This module contains unit-typed translations, rotations, poses, transforms and twists built on nalgebra.
+//
+// ### [Math](crate::math)
+//
+// This module contains unit-typed control utilities such as clamping, slew rate limiting, filters,
+// PID controllers, feedforwards and motion profiles.
+//
 
 #![deny(clippy::all, clippy::pedantic, clippy::nursery)]
 #![deny(
@@ -92,8 +101,12 @@
 )]
 #![cfg_attr(not(test), warn(missing_docs))]
 
+#[cfg(feature = "geometry")]
+pub mod geometry;
 #[cfg(feature = "hal")]
 pub mod hal;
+#[cfg(feature = "math")]
+pub mod math;
 #[cfg(feature = "structure")]
 pub mod structure;
 #[cfg(feature = "time")]
@@ -0,0 +1,71 @@
+use crate::time::Instant;
+use crate::units::time::{Second, Time};
+
+/// Which edges of the input a [`Debouncer`] delays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebounceType {
+    /// Only `false` to `true` changes are delayed
+    Rising,
+    /// Only `true` to `false` changes are delayed
+    Falling,
+    /// Both changes are delayed
+    Both,
+}
+
+/// Ignores changes of a boolean input that don't last for the debounce time.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::{DebounceType, Debouncer};
+/// use frclib_core::time::{Duration, Instant};
+/// use frclib_core::units::time::Millisecond;
+///
+/// let start = Instant::now();
+/// let mut debouncer = Debouncer::new(Millisecond::new(100.0), DebounceType::Rising);
+/// assert!(!debouncer.calculate_at(true, start + Duration::from_millis(50)));
+/// assert!(debouncer.calculate_at(true, start + Duration::from_millis(150)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Debouncer {
+    debounce_time: Second,
+    previous_time: Instant,
+    debounce_type: DebounceType,
+    base_value: bool,
+}
+
+impl Debouncer {
+    /// Creates a debouncer whose output starts at the resting value of `debounce_type`,
+    /// `false` for [`Rising`](DebounceType::Rising) and [`Both`](DebounceType::Both)
+    /// and `true` for [`Falling`](DebounceType::Falling)
+    #[must_use]
+    pub fn new(debounce_time: impl Time, debounce_type: DebounceType) -> Self {
+        Self {
+            debounce_time: debounce_time.standard(),
+            previous_time: Instant::now(),
+            debounce_type,
+            base_value: debounce_type == DebounceType::Falling,
+        }
+    }
+
+    /// Debounces `input` at the current time
+    pub fn calculate(&mut self, input: bool) -> bool {
+        self.calculate_at(input, Instant::now())
+    }
+
+    /// Debounces `input` as of `now`
+    pub fn calculate_at(&mut self, input: bool, now: Instant) -> bool {
+        if input == self.base_value {
+            self.previous_time = now;
+        }
+        let elapsed = Second::from(now.saturating_duration_since(self.previous_time));
+        if elapsed >= self.debounce_time {
+            if self.debounce_type == DebounceType::Both {
+                self.base_value = input;
+                self.previous_time = now;
+            }
+            input
+        } else {
+            self.base_value
+        }
+    }
+}
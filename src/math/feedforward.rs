@@ -0,0 +1,220 @@
+use std::marker::PhantomData;
+
+use crate::units::angle::{Angle, Radian};
+use crate::units::length::Meter;
+use crate::units::mechanism::Kinematic;
+use crate::units::voltage::{Volt, Voltage};
+
+/// The voltage to overcome friction and back EMF of a motor driving a mechanism without gravity,
+/// like a flywheel or drivetrain.
+///
+/// `K` is the position unit of the mechanism, like [`Radian`] for angular and [`Meter`] for linear mechanisms.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::SimpleMotorFeedforward;
+/// use frclib_core::units::angle::Radian;
+/// use frclib_core::units::angular_acceleration::RotationPerSecSqr;
+/// use frclib_core::units::angular_velocity::RotationPerMin;
+/// use frclib_core::units::voltage::Volt;
+///
+/// // 0.2 V of friction and 12 V to spin at 6000 rpm
+/// let flywheel = SimpleMotorFeedforward::<Radian>::new(Volt::new(0.2))
+///     .with_v(Volt::new(11.8), RotationPerMin::new(6000.0));
+/// let volts = flywheel.calculate(RotationPerMin::new(3000.0), RotationPerSecSqr::new(0.0));
+/// assert!((volts.value() - 6.1).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimpleMotorFeedforward<K: Kinematic> {
+    ks: f64,
+    kv: f64,
+    ka: f64,
+    kind: PhantomData<K>,
+}
+
+impl<K: Kinematic> SimpleMotorFeedforward<K> {
+    /// Creates a feedforward with `ks` of voltage to overcome static friction
+    #[must_use]
+    pub fn new(ks: impl Voltage) -> Self {
+        Self {
+            ks: ks.standard().value(),
+            kv: 0.0,
+            ka: 0.0,
+            kind: PhantomData,
+        }
+    }
+
+    /// Sets the velocity gain to `voltage` for every `velocity`
+    #[must_use]
+    pub fn with_v(mut self, voltage: impl Voltage, velocity: impl Into<K::Velocity>) -> Self {
+        self.kv = voltage.standard().value() / velocity.into().into();
+        self
+    }
+
+    /// Sets the acceleration gain to `voltage` for every `acceleration`
+    #[must_use]
+    pub fn with_a(
+        mut self,
+        voltage: impl Voltage,
+        acceleration: impl Into<K::Acceleration>,
+    ) -> Self {
+        self.ka = voltage.standard().value() / acceleration.into().into();
+        self
+    }
+
+    /// The voltage to move at `velocity` while accelerating at `acceleration`
+    #[must_use]
+    pub fn calculate(
+        &self,
+        velocity: impl Into<K::Velocity>,
+        acceleration: impl Into<K::Acceleration>,
+    ) -> Volt {
+        let (velocity, acceleration): (f64, f64) =
+            (velocity.into().into(), acceleration.into().into());
+        Volt::new(self.kv.mul_add(
+            velocity,
+            self.ka.mul_add(acceleration, self.ks * signum(velocity)),
+        ))
+    }
+}
+
+/// The voltage to hold and move an arm against gravity.
+///
+/// The angle is measured from horizontal, gravity pulls hardest on a level arm.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::ArmFeedforward;
+/// use frclib_core::units::angle::Degree;
+/// use frclib_core::units::angular_acceleration::RadianPerSecSqr;
+/// use frclib_core::units::angular_velocity::RadianPerSec;
+/// use frclib_core::units::voltage::Volt;
+///
+/// let arm = ArmFeedforward::new(Volt::new(0.1), Volt::new(0.8));
+/// let hold = arm.calculate(Degree::new(60.0), RadianPerSec::new(0.0), RadianPerSecSqr::new(0.0));
+/// assert!((hold.value() - 0.4).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArmFeedforward {
+    motor: SimpleMotorFeedforward<Radian>,
+    kg: f64,
+}
+
+impl ArmFeedforward {
+    /// Creates a feedforward with `ks` to overcome static friction
+    /// and `kg` to hold the arm level
+    #[must_use]
+    pub fn new(ks: impl Voltage, kg: impl Voltage) -> Self {
+        Self {
+            motor: SimpleMotorFeedforward::new(ks),
+            kg: kg.standard().value(),
+        }
+    }
+
+    /// Sets the velocity gain to `voltage` for every `velocity`
+    #[must_use]
+    pub fn with_v(
+        mut self,
+        voltage: impl Voltage,
+        velocity: impl Into<<Radian as Kinematic>::Velocity>,
+    ) -> Self {
+        self.motor = self.motor.with_v(voltage, velocity);
+        self
+    }
+
+    /// Sets the acceleration gain to `voltage` for every `acceleration`
+    #[must_use]
+    pub fn with_a(
+        mut self,
+        voltage: impl Voltage,
+        acceleration: impl Into<<Radian as Kinematic>::Acceleration>,
+    ) -> Self {
+        self.motor = self.motor.with_a(voltage, acceleration);
+        self
+    }
+
+    /// The voltage to move through `angle` at `velocity` while accelerating at `acceleration`
+    #[must_use]
+    pub fn calculate(
+        &self,
+        angle: impl Angle,
+        velocity: impl Into<<Radian as Kinematic>::Velocity>,
+        acceleration: impl Into<<Radian as Kinematic>::Acceleration>,
+    ) -> Volt {
+        self.motor.calculate(velocity, acceleration) + self.kg * angle.standard().value().cos()
+    }
+}
+
+/// The voltage to hold and move an elevator against gravity.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::ElevatorFeedforward;
+/// use frclib_core::units::linear_acceleration::MetersPerSecSqr;
+/// use frclib_core::units::linear_velocity::MetersPerSecond;
+/// use frclib_core::units::voltage::Volt;
+///
+/// let elevator = ElevatorFeedforward::new(Volt::new(0.1), Volt::new(0.5))
+///     .with_v(Volt::new(10.0), MetersPerSecond::new(2.0));
+/// let up = elevator.calculate(MetersPerSecond::new(1.0), MetersPerSecSqr::new(0.0));
+/// assert!((up.value() - 5.6).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElevatorFeedforward {
+    motor: SimpleMotorFeedforward<Meter>,
+    kg: f64,
+}
+
+impl ElevatorFeedforward {
+    /// Creates a feedforward with `ks` to overcome static friction
+    /// and `kg` to hold the carriage still
+    #[must_use]
+    pub fn new(ks: impl Voltage, kg: impl Voltage) -> Self {
+        Self {
+            motor: SimpleMotorFeedforward::new(ks),
+            kg: kg.standard().value(),
+        }
+    }
+
+    /// Sets the velocity gain to `voltage` for every `velocity`
+    #[must_use]
+    pub fn with_v(
+        mut self,
+        voltage: impl Voltage,
+        velocity: impl Into<<Meter as Kinematic>::Velocity>,
+    ) -> Self {
+        self.motor = self.motor.with_v(voltage, velocity);
+        self
+    }
+
+    /// Sets the acceleration gain to `voltage` for every `acceleration`
+    #[must_use]
+    pub fn with_a(
+        mut self,
+        voltage: impl Voltage,
+        acceleration: impl Into<<Meter as Kinematic>::Acceleration>,
+    ) -> Self {
+        self.motor = self.motor.with_a(voltage, acceleration);
+        self
+    }
+
+    /// The voltage to move at `velocity` while accelerating at `acceleration`
+    #[must_use]
+    pub fn calculate(
+        &self,
+        velocity: impl Into<<Meter as Kinematic>::Velocity>,
+        acceleration: impl Into<<Meter as Kinematic>::Acceleration>,
+    ) -> Volt {
+        self.motor.calculate(velocity, acceleration) + self.kg
+    }
+}
+
+/// The sign of `value` with zero staying zero, so a stopped mechanism gets no friction voltage
+fn signum(value: f64) -> f64 {
+    #[allow(clippy::float_cmp)]
+    if value == 0.0 {
+        0.0
+    } else {
+        value.signum()
+    }
+}
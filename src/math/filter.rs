@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use crate::units::time::Time;
+
+use super::{raw, MathUnit};
+
+/// A finite or infinite impulse response filter of a unit.
+///
+/// The output is `Σ input_gains[i] * x[n - i] - Σ output_gains[i] * y[n - i - 1]`,
+/// the constructors cover the common moving average, low pass and high pass filters.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::LinearFilter;
+/// use frclib_core::units::length::Meter;
+///
+/// let mut filter = LinearFilter::moving_average(2);
+/// assert_eq!(filter.calculate(Meter::new(2.0)), Meter::new(1.0));
+/// assert_eq!(filter.calculate(Meter::new(4.0)), Meter::new(3.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearFilter<U> {
+    input_gains: Vec<f64>,
+    output_gains: Vec<f64>,
+    inputs: VecDeque<f64>,
+    outputs: VecDeque<f64>,
+    last_output: f64,
+    unit: PhantomData<U>,
+}
+
+impl<U: MathUnit> LinearFilter<U> {
+    /// Creates a filter from its input and output gains, see the [type docs](Self)
+    #[must_use]
+    pub fn new(input_gains: Vec<f64>, output_gains: Vec<f64>) -> Self {
+        Self {
+            inputs: VecDeque::with_capacity(input_gains.len()),
+            outputs: VecDeque::with_capacity(output_gains.len()),
+            input_gains,
+            output_gains,
+            last_output: 0.0,
+            unit: PhantomData,
+        }
+    }
+
+    /// Creates a filter that averages the last `taps` inputs,
+    /// missing inputs count as zero until the filter has seen `taps` of them
+    #[must_use]
+    pub fn moving_average(taps: usize) -> Self {
+        let taps = taps.max(1);
+        #[allow(clippy::cast_precision_loss)]
+        let gain = (taps as f64).recip();
+        Self::new(vec![gain; taps], Vec::new())
+    }
+
+    /// Creates a single pole low pass filter with the given time constant,
+    /// called every `period`
+    #[must_use]
+    pub fn single_pole_iir(time_constant: impl Time, period: impl Time) -> Self {
+        let gain = (-period.standard().value() / time_constant.standard().value()).exp();
+        Self::new(vec![1.0 - gain], vec![-gain])
+    }
+
+    /// Creates a single pole high pass filter with the given time constant,
+    /// called every `period`
+    #[must_use]
+    pub fn high_pass(time_constant: impl Time, period: impl Time) -> Self {
+        let gain = (-period.standard().value() / time_constant.standard().value()).exp();
+        Self::new(vec![gain, -gain], vec![-gain])
+    }
+
+    /// Filters the next input
+    pub fn calculate(&mut self, input: U) -> U {
+        push_limited(&mut self.inputs, raw(input), self.input_gains.len());
+        let output = dot(&self.input_gains, &self.inputs) - dot(&self.output_gains, &self.outputs);
+        push_limited(&mut self.outputs, output, self.output_gains.len());
+        self.last_output = output;
+        U::from(output)
+    }
+
+    /// The last output of the filter, zero before the first input
+    #[must_use]
+    pub fn last_value(&self) -> U {
+        U::from(self.last_output)
+    }
+
+    /// Forgets every previous input and output
+    pub fn reset(&mut self) {
+        self.inputs.clear();
+        self.outputs.clear();
+        self.last_output = 0.0;
+    }
+}
+
+/// Filters out outliers by returning the median of the last few inputs.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::MedianFilter;
+/// use frclib_core::units::length::Meter;
+///
+/// let mut filter = MedianFilter::new(3);
+/// let _ = filter.calculate(Meter::new(1.0));
+/// let _ = filter.calculate(Meter::new(100.0));
+/// assert_eq!(filter.calculate(Meter::new(2.0)), Meter::new(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MedianFilter<U> {
+    size: usize,
+    window: VecDeque<f64>,
+    unit: PhantomData<U>,
+}
+
+impl<U: MathUnit> MedianFilter<U> {
+    /// Creates a filter over the last `size` inputs
+    #[must_use]
+    pub fn new(size: usize) -> Self {
+        let size = size.max(1);
+        Self {
+            size,
+            window: VecDeque::with_capacity(size),
+            unit: PhantomData,
+        }
+    }
+
+    /// Filters the next input,
+    /// the median of an even number of inputs is the mean of the middle two
+    pub fn calculate(&mut self, input: U) -> U {
+        push_limited(&mut self.window, raw(input), self.size);
+        let mut sorted: Vec<f64> = self.window.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let middle = sorted.len() / 2;
+        let upper = sorted.get(middle).copied().unwrap_or_default();
+        if sorted.len() % 2 == 0 {
+            let lower = sorted.get(middle - 1).copied().unwrap_or_default();
+            U::from((lower + upper) / 2.0)
+        } else {
+            U::from(upper)
+        }
+    }
+
+    /// Forgets every previous input
+    pub fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Pushes the newest value to the front and drops the oldest past `limit`
+fn push_limited(values: &mut VecDeque<f64>, value: f64, limit: usize) {
+    if limit == 0 {
+        return;
+    }
+    values.push_front(value);
+    values.truncate(limit);
+}
+
+fn dot(gains: &[f64], values: &VecDeque<f64>) -> f64 {
+    gains
+        .iter()
+        .zip(values)
+        .map(|(gain, value)| gain * value)
+        .sum()
+}
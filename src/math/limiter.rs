@@ -0,0 +1,95 @@
+use crate::time::Instant;
+use crate::units::time::Time;
+
+use super::{raw, MathUnit};
+
+/// Limits how fast a value can change, like the acceleration of a joystick-driven drivetrain.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::SlewRateLimiter;
+/// use frclib_core::units::linear_velocity::MetersPerSecond;
+/// use frclib_core::units::time::{Millisecond, Second};
+///
+/// // at most 2 m/s of change per second
+/// let mut limiter = SlewRateLimiter::new(MetersPerSecond::new(2.0), Second::new(1.0));
+/// let speed = limiter.calculate_dt(MetersPerSecond::new(3.0), Millisecond::new(100.0));
+/// assert!((speed.value() - 0.2).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SlewRateLimiter<U> {
+    positive_rate: f64,
+    negative_rate: f64,
+    previous: U,
+    previous_time: Option<Instant>,
+}
+
+impl<U: MathUnit> SlewRateLimiter<U> {
+    /// Creates a limiter that changes by at most `rate` every `per`
+    #[must_use]
+    pub fn new(rate: impl Into<U::Delta>, per: impl Time) -> Self {
+        let rate = rate.into();
+        Self::with_rates(rate, rate, per)
+    }
+
+    /// Creates a limiter that rises by at most `positive` and falls by at most `negative` every `per`,
+    /// the signs of the rates are ignored and a `per` of zero does not limit the value
+    #[must_use]
+    pub fn with_rates(
+        positive: impl Into<U::Delta>,
+        negative: impl Into<U::Delta>,
+        per: impl Time,
+    ) -> Self {
+        let per = per.standard().value().abs();
+        Self {
+            positive_rate: raw(positive.into()).abs() / per,
+            negative_rate: -raw(negative.into()).abs() / per,
+            previous: U::from(0.0),
+            previous_time: None,
+        }
+    }
+
+    /// Limits `input` using the time since the last call, the first call is not limited
+    pub fn calculate(&mut self, input: U) -> U {
+        self.calculate_at(input, Instant::now())
+    }
+
+    /// Limits `input` using the time from the last call to `now`, the first call is not limited
+    pub fn calculate_at(&mut self, input: U, now: Instant) -> U {
+        if let Some(previous) = self.previous_time.replace(now) {
+            let elapsed = now.saturating_duration_since(previous).as_secs_f64();
+            self.step(input, elapsed)
+        } else {
+            self.previous = input;
+            input
+        }
+    }
+
+    /// Limits `input` assuming `dt` has passed since the last value
+    pub fn calculate_dt(&mut self, input: U, dt: impl Time) -> U {
+        self.step(input, dt.standard().value())
+    }
+
+    /// The last value the limiter returned
+    #[must_use]
+    pub const fn last_value(&self) -> U {
+        self.previous
+    }
+
+    /// Resets the limiter to `value` and forgets the time of the last call
+    pub fn reset(&mut self, value: impl Into<U>) {
+        self.previous = value.into();
+        self.previous_time = None;
+    }
+
+    fn step(&mut self, input: U, elapsed: f64) -> U {
+        let previous = raw(self.previous);
+        let elapsed = elapsed.max(0.0);
+        // unlike `clamp` this never panics, a NaN bound from an infinite rate is ignored
+        let change = (raw(input) - previous)
+            .max(self.negative_rate * elapsed)
+            .min(self.positive_rate * elapsed);
+        self.previous = U::from(previous + change);
+        self.previous
+    }
+}
@@ -0,0 +1,117 @@
+//! Unit-typed control math.
+//!
+//! Every helper here is generic over the unit it works in, bounds and rates are accepted in
+//! any unit convertible to it so a limit given in degrees can clamp a value in radians.
+//! Rates, bands and tolerances are differences, for a `point` unit like
+//! [`Celsius`](crate::units::temperature::Celsius) they are given in its delta unit.
+//! The controllers take their gains as the output wanted for an amount of input,
+//! so a gain can't be given in the wrong unit.
+//!
+//! # Example
+//! ```
+//! use frclib_core::math::{clamp, deadband, lerp};
+//! use frclib_core::units::angle::{Degree, Radian};
+//! use frclib_core::units::linear_velocity::MetersPerSecond;
+//!
+//! let angle = clamp(Radian::new(2.0), Degree::new(-90.0), Degree::new(90.0));
+//! assert_eq!(angle, Radian::new(std::f64::consts::FRAC_PI_2));
+//!
+//! let speed = deadband(MetersPerSecond::new(0.05), MetersPerSecond::new(0.1), MetersPerSecond::new(4.0));
+//! assert_eq!(speed, MetersPerSecond::new(0.0));
+//!
+//! assert_eq!(lerp(Degree::new(10.0), Degree::new(20.0), 0.25), Degree::new(12.5));
+//! ```
+
+use std::ops::Sub;
+
+mod debouncer;
+mod feedforward;
+mod filter;
+mod limiter;
+mod pid;
+mod profile;
+#[cfg(test)]
+mod test;
+
+pub use debouncer::{DebounceType, Debouncer};
+pub use feedforward::{ArmFeedforward, ElevatorFeedforward, SimpleMotorFeedforward};
+pub use filter::{LinearFilter, MedianFilter};
+pub use limiter::SlewRateLimiter;
+pub use pid::PIDController;
+pub use profile::{ExponentialProfile, ProfileState, TrapezoidProfile};
+
+/// A unit the math utilities can compute with.
+///
+/// This is implemented for every `float` and `point` unit and for [`f64`] itself.
+pub trait MathUnit: Copy + PartialOrd + From<f64> + Into<f64> + Sub<Output = Self::Delta> {
+    /// The unit of the difference between two values,
+    /// the unit itself for everything except `point` units
+    type Delta: Copy + PartialOrd + From<f64> + Into<f64>;
+}
+
+impl<T> MathUnit for T
+where
+    T: Copy + PartialOrd + From<f64> + Into<f64> + Sub,
+    <T as Sub>::Output: Copy + PartialOrd + From<f64> + Into<f64>,
+{
+    type Delta = <T as Sub>::Output;
+}
+
+fn raw<U: Into<f64>>(value: U) -> f64 {
+    value.into()
+}
+
+/// Restricts `value` to the range `[low, high]`
+#[must_use]
+pub fn clamp<U: MathUnit>(value: U, low: impl Into<U>, high: impl Into<U>) -> U {
+    let (low, high) = (low.into(), high.into());
+    if value < low {
+        low
+    } else if value > high {
+        high
+    } else {
+        value
+    }
+}
+
+/// The value `t` of the way from `start` to `end`, `t` is not clamped
+#[must_use]
+pub fn lerp<U: MathUnit>(start: U, end: impl Into<U>, t: f64) -> U {
+    let (start, end) = (raw(start), raw(end.into()));
+    U::from((end - start).mul_add(t, start))
+}
+
+/// How far of the way `value` is from `start` to `end`, the inverse of [`lerp`].
+///
+/// Returns `0` if `start` and `end` are equal.
+#[must_use]
+pub fn inverse_lerp<U: MathUnit>(start: U, end: impl Into<U>, value: impl Into<U>) -> f64 {
+    let (start, end, value) = (raw(start), raw(end.into()), raw(value.into()));
+    let span = end - start;
+    #[allow(clippy::float_cmp)]
+    if span == 0.0 {
+        0.0
+    } else {
+        (value - start) / span
+    }
+}
+
+/// Zeroes `value` inside `(-band, band)` and rescales the rest so the output still
+/// reaches `max_magnitude` continuously from zero at the edge of the band
+#[must_use]
+pub fn deadband<U: MathUnit>(
+    value: U,
+    band: impl Into<U::Delta>,
+    max_magnitude: impl Into<U::Delta>,
+) -> U {
+    let (value, band, max) = (
+        raw(value),
+        raw(band.into()).abs(),
+        raw(max_magnitude.into()).abs(),
+    );
+    if value.abs() < band || max <= band {
+        U::from(0.0)
+    } else {
+        U::from((value - band.copysign(value)) * max / (max - band))
+    }
+}
@@ -0,0 +1,209 @@
+use std::marker::PhantomData;
+
+use crate::time::Instant;
+use crate::units::time::Time;
+
+use super::{raw, MathUnit};
+
+/// A PID controller that drives an `I` measurement to a setpoint with an `O` output.
+///
+/// The gains are given as the output wanted for an amount of error so their units can't be mixed up,
+/// errors and tolerances are differences in the delta unit of `I` when it is a `point` unit.
+/// [`with_p`](Self::with_p) takes the output for an error, [`with_i`](Self::with_i) the output for
+/// an error held for some time and [`with_d`](Self::with_d) the output for an error changing by
+/// an amount over some time.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::PIDController;
+/// use frclib_core::units::angle::{Degree, Radian};
+/// use frclib_core::units::time::Millisecond;
+/// use frclib_core::units::voltage::Volt;
+///
+/// // 1 volt for every 10 degrees of error
+/// let mut pid = PIDController::<Radian, Volt>::new()
+///     .with_p(Volt::new(1.0), Degree::new(10.0));
+/// pid.enable_continuous_input(Degree::new(-180.0), Degree::new(180.0));
+/// pid.set_setpoint(Degree::new(170.0));
+///
+/// // the short way around from -170° is 20° backwards
+/// let output = pid.calculate_dt(Degree::new(-170.0), Millisecond::new(20.0));
+/// assert!((output.value() + 2.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PIDController<I, O> {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    setpoint: f64,
+    tolerance: f64,
+    continuous_range: Option<f64>,
+    integrator_range: (f64, f64),
+    total_error: f64,
+    previous_error: Option<f64>,
+    previous_time: Option<Instant>,
+    units: PhantomData<(I, O)>,
+}
+
+impl<I: MathUnit, O: MathUnit> Default for PIDController<I, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: MathUnit, O: MathUnit> PIDController<I, O> {
+    /// Creates a controller with all gains at zero and a setpoint of zero
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            kp: 0.0,
+            ki: 0.0,
+            kd: 0.0,
+            setpoint: 0.0,
+            tolerance: 0.0,
+            continuous_range: None,
+            integrator_range: (f64::NEG_INFINITY, f64::INFINITY),
+            total_error: 0.0,
+            previous_error: None,
+            previous_time: None,
+            units: PhantomData,
+        }
+    }
+
+    /// Sets the proportional gain to `output` for every `error`
+    #[must_use]
+    pub fn with_p(mut self, output: impl Into<O>, error: impl Into<I::Delta>) -> Self {
+        self.kp = raw(output.into()) / raw(error.into());
+        self
+    }
+
+    /// Sets the integral gain to `output` for every `error` held for `time`
+    #[must_use]
+    pub fn with_i(
+        mut self,
+        output: impl Into<O>,
+        error: impl Into<I::Delta>,
+        time: impl Time,
+    ) -> Self {
+        self.ki = raw(output.into()) / (raw(error.into()) * time.standard().value());
+        self
+    }
+
+    /// Sets the derivative gain to `output` for an error changing by `error` every `time`
+    #[must_use]
+    pub fn with_d(
+        mut self,
+        output: impl Into<O>,
+        error: impl Into<I::Delta>,
+        time: impl Time,
+    ) -> Self {
+        self.kd = raw(output.into()) * time.standard().value() / raw(error.into());
+        self
+    }
+
+    /// The proportional, integral and derivative gains in `O` per `I`, per `I` second
+    /// and per `I` per second
+    #[must_use]
+    pub const fn gains(&self) -> (f64, f64, f64) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Sets the value the controller drives the measurement to
+    pub fn set_setpoint(&mut self, setpoint: impl Into<I>) {
+        self.setpoint = raw(setpoint.into());
+    }
+
+    /// The value the controller drives the measurement to
+    #[must_use]
+    pub fn setpoint(&self) -> I {
+        I::from(self.setpoint)
+    }
+
+    /// Treats `min` and `max` as the same point so the error always takes the shortest way around,
+    /// like an angle from `-180°` to `180°`
+    pub fn enable_continuous_input(&mut self, min: impl Into<I>, max: impl Into<I>) {
+        let range: I::Delta = max.into() - min.into();
+        self.continuous_range = Some(raw(range).abs());
+    }
+
+    /// Stops treating the input as continuous
+    pub fn disable_continuous_input(&mut self) {
+        self.continuous_range = None;
+    }
+
+    /// Limits the output of the integral term to `[min, max]`
+    pub fn set_integrator_range(&mut self, min: impl Into<O>, max: impl Into<O>) {
+        self.integrator_range = (raw(min.into()), raw(max.into()));
+    }
+
+    /// Sets how close the measurement has to be to count as [at the setpoint](Self::at_setpoint)
+    pub fn set_tolerance(&mut self, tolerance: impl Into<I::Delta>) {
+        self.tolerance = raw(tolerance.into()).abs();
+    }
+
+    /// The error of the last measurement, zero before the first measurement
+    #[must_use]
+    pub fn error(&self) -> I::Delta {
+        I::Delta::from(self.previous_error.unwrap_or_default())
+    }
+
+    /// If the last measurement was within the tolerance of the setpoint
+    #[must_use]
+    pub fn at_setpoint(&self) -> bool {
+        self.previous_error
+            .is_some_and(|error| error.abs() <= self.tolerance)
+    }
+
+    /// Calculates the output for `measurement` using the time since the last call,
+    /// the first call only uses the proportional term
+    pub fn calculate(&mut self, measurement: impl Into<I>) -> O {
+        self.calculate_at(measurement, Instant::now())
+    }
+
+    /// Calculates the output for `measurement` using the time from the last call to `now`,
+    /// the first call only uses the proportional term
+    pub fn calculate_at(&mut self, measurement: impl Into<I>, now: Instant) -> O {
+        let dt = self
+            .previous_time
+            .replace(now)
+            .map(|previous| now.saturating_duration_since(previous).as_secs_f64());
+        self.step(raw(measurement.into()), dt)
+    }
+
+    /// Calculates the output for `measurement` assuming `dt` has passed since the last call
+    pub fn calculate_dt(&mut self, measurement: impl Into<I>, dt: impl Time) -> O {
+        self.step(raw(measurement.into()), Some(dt.standard().value()))
+    }
+
+    /// Clears the accumulated integral and the previous error
+    pub fn reset(&mut self) {
+        self.total_error = 0.0;
+        self.previous_error = None;
+        self.previous_time = None;
+    }
+
+    fn step(&mut self, measurement: f64, dt: Option<f64>) -> O {
+        let mut error = self.setpoint - measurement;
+        if let Some(range) = self.continuous_range {
+            error = (error + range / 2.0).rem_euclid(range) - range / 2.0;
+        }
+        let mut output = self.kp * error;
+        if let Some(dt) = dt.filter(|dt| *dt > 0.0) {
+            self.total_error += error * dt;
+            #[allow(clippy::float_cmp)]
+            if self.ki != 0.0 {
+                let (min, max) = self.integrator_range;
+                self.total_error = self.total_error.clamp(
+                    (min / self.ki).min(max / self.ki),
+                    (min / self.ki).max(max / self.ki),
+                );
+            }
+            output += self.ki * self.total_error;
+            if let Some(previous) = self.previous_error {
+                output += self.kd * (error - previous) / dt;
+            }
+        }
+        self.previous_error = Some(error);
+        O::from(output)
+    }
+}
@@ -0,0 +1,375 @@
+use crate::units::mechanism::Kinematic;
+use crate::units::time::{Second, Time};
+
+/// A position and velocity along a motion profile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfileState<P: Kinematic> {
+    /// The position of the state
+    pub position: P,
+    /// The velocity of the state
+    pub velocity: P::Velocity,
+}
+
+impl<P: Kinematic> ProfileState<P> {
+    /// Creates a state at `position` moving at `velocity`
+    #[must_use]
+    pub fn new(position: impl Into<P>, velocity: impl Into<P::Velocity>) -> Self {
+        Self {
+            position: position.into(),
+            velocity: velocity.into(),
+        }
+    }
+
+    /// Creates a state resting at `position`
+    #[must_use]
+    pub fn at_rest(position: impl Into<P>) -> Self {
+        Self::new(position, P::Velocity::from(0.0))
+    }
+
+    fn raw(self) -> (f64, f64) {
+        (self.position.into(), self.velocity.into())
+    }
+
+    fn from_raw(position: f64, velocity: f64) -> Self {
+        Self::new(P::from(position), P::Velocity::from(velocity))
+    }
+}
+
+/// A motion profile that accelerates at a constant rate up to a maximum velocity,
+/// cruises, then decelerates to reach the goal.
+///
+/// `P` is the position unit of the mechanism, like [`Radian`](crate::units::angle::Radian) for
+/// angular and [`Meter`](crate::units::length::Meter) for linear mechanisms,
+/// any [`Kinematic`] unit such as a [`Degree`](crate::units::angle::Degree) works.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::{ProfileState, TrapezoidProfile};
+/// use frclib_core::units::length::Meter;
+/// use frclib_core::units::linear_acceleration::MetersPerSecSqr;
+/// use frclib_core::units::linear_velocity::MetersPerSecond;
+/// use frclib_core::units::time::Second;
+///
+/// let profile = TrapezoidProfile::<Meter>::new(MetersPerSecond::new(1.0), MetersPerSecSqr::new(2.0));
+/// let (start, goal) = (ProfileState::at_rest(Meter::new(0.0)), ProfileState::at_rest(Meter::new(2.0)));
+///
+/// // half a second to reach full speed, 1.5 seconds of cruising and half a second to stop
+/// assert!((profile.total_time(start, goal).value() - 2.5).abs() < 1e-9);
+/// let middle = profile.calculate(Second::new(1.25), start, goal);
+/// assert!((middle.position.value() - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrapezoidProfile<P: Kinematic> {
+    max_velocity: P::Velocity,
+    max_acceleration: P::Acceleration,
+}
+
+/// The times each phase of a trapezoid profile ends, after flipping it to move forward
+struct TrapezoidPhases {
+    direction: f64,
+    current: (f64, f64),
+    goal: (f64, f64),
+    end_accel: f64,
+    end_full_speed: f64,
+    end_decel: f64,
+}
+
+impl<P: Kinematic> TrapezoidProfile<P> {
+    /// Creates a profile limited to `max_velocity` and `max_acceleration`
+    #[must_use]
+    pub fn new(
+        max_velocity: impl Into<P::Velocity>,
+        max_acceleration: impl Into<P::Acceleration>,
+    ) -> Self {
+        Self {
+            max_velocity: P::Velocity::from(max_velocity.into().into().abs()),
+            max_acceleration: P::Acceleration::from(max_acceleration.into().into().abs()),
+        }
+    }
+
+    /// The state `t` into the profile from `current` to `goal`
+    #[must_use]
+    pub fn calculate(
+        &self,
+        t: impl Time,
+        current: ProfileState<P>,
+        goal: ProfileState<P>,
+    ) -> ProfileState<P> {
+        let t = t.standard().value();
+        let accel: f64 = self.max_acceleration.into();
+        let phases = self.phases(current, goal);
+        let (position, velocity) = phases.current;
+        let (position, velocity) = if t < phases.end_accel {
+            (
+                (velocity + t * accel / 2.0).mul_add(t, position),
+                t.mul_add(accel, velocity),
+            )
+        } else if t < phases.end_full_speed {
+            let max_velocity: f64 = self.max_velocity.into();
+            let accel_distance = (velocity + phases.end_accel * accel / 2.0) * phases.end_accel;
+            (
+                max_velocity.mul_add(t - phases.end_accel, position + accel_distance),
+                max_velocity,
+            )
+        } else if t <= phases.end_decel {
+            let (goal_position, goal_velocity) = phases.goal;
+            let time_left = phases.end_decel - t;
+            (
+                (goal_velocity + time_left * accel / 2.0).mul_add(-time_left, goal_position),
+                time_left.mul_add(accel, goal_velocity),
+            )
+        } else {
+            phases.goal
+        };
+        ProfileState::from_raw(position * phases.direction, velocity * phases.direction)
+    }
+
+    /// The time the profile from `current` to `goal` takes
+    #[must_use]
+    pub fn total_time(&self, current: ProfileState<P>, goal: ProfileState<P>) -> Second {
+        Second::new(self.phases(current, goal).end_decel)
+    }
+
+    fn phases(&self, current: ProfileState<P>, goal: ProfileState<P>) -> TrapezoidPhases {
+        let (max_velocity, accel): (f64, f64) =
+            (self.max_velocity.into(), self.max_acceleration.into());
+        let direction = if current.position.into() > goal.position.into() {
+            -1.0
+        } else {
+            1.0
+        };
+        let (current_position, current_velocity) = current.raw();
+        let (goal_position, goal_velocity) = goal.raw();
+        let current = (
+            current_position * direction,
+            (current_velocity * direction).min(max_velocity),
+        );
+        let goal = (goal_position * direction, goal_velocity * direction);
+
+        // the profile is a full trapezoid starting and ending at rest, cut off at both ends
+        let cutoff_begin = current.1 / accel;
+        let cutoff_end = goal.1 / accel;
+        let full_distance = (cutoff_begin * cutoff_begin).mul_add(
+            accel / 2.0,
+            (cutoff_end * cutoff_end).mul_add(accel / 2.0, goal.0 - current.0),
+        );
+        let mut accel_time = max_velocity / accel;
+        let mut full_speed_distance = (accel_time * accel_time).mul_add(-accel, full_distance);
+        if full_speed_distance < 0.0 {
+            accel_time = (full_distance / accel).sqrt();
+            full_speed_distance = 0.0;
+        }
+
+        let end_accel = accel_time - cutoff_begin;
+        let end_full_speed = end_accel + full_speed_distance / max_velocity;
+        TrapezoidPhases {
+            direction,
+            current,
+            goal,
+            end_accel,
+            end_full_speed,
+            end_decel: end_full_speed + accel_time - cutoff_end,
+        }
+    }
+}
+
+/// A motion profile for a mechanism driven by a motor, whose acceleration falls off
+/// exponentially as it approaches its top speed instead of staying constant.
+///
+/// The constraints give the top speed and the acceleration from rest,
+/// the profile accelerates as hard as the motor can towards the goal then brakes as hard as it can.
+///
+/// # Example
+/// ```
+/// use frclib_core::math::{ExponentialProfile, ProfileState};
+/// use frclib_core::units::angle::{Degree, Radian};
+/// use frclib_core::units::angular_acceleration::RadianPerSecSqr;
+/// use frclib_core::units::angular_velocity::RadianPerSec;
+/// use frclib_core::units::time::Second;
+///
+/// let profile = ExponentialProfile::<Radian>::new(RadianPerSec::new(4.0), RadianPerSecSqr::new(8.0));
+/// let (start, goal) = (ProfileState::at_rest(Degree::new(0.0)), ProfileState::at_rest(Degree::new(90.0)));
+///
+/// let total = profile.total_time(start, goal);
+/// let almost = profile.calculate(total - Second::new(0.001), start, goal);
+/// assert!((Degree::from(almost.position).value() - 90.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialProfile<P: Kinematic> {
+    max_velocity: P::Velocity,
+    max_acceleration: P::Acceleration,
+}
+
+/// When an exponential profile switches from accelerating to braking and when it ends
+struct ExponentialTiming {
+    input: f64,
+    inflection_time: f64,
+    total_time: f64,
+}
+
+impl<P: Kinematic> ExponentialProfile<P> {
+    /// Creates a profile with a top speed of `max_velocity` that accelerates
+    /// at `max_acceleration` from rest
+    #[must_use]
+    pub fn new(
+        max_velocity: impl Into<P::Velocity>,
+        max_acceleration: impl Into<P::Acceleration>,
+    ) -> Self {
+        Self {
+            max_velocity: P::Velocity::from(max_velocity.into().into().abs()),
+            max_acceleration: P::Acceleration::from(max_acceleration.into().into().abs()),
+        }
+    }
+
+    /// The state `t` into the profile from `current` to `goal`
+    #[must_use]
+    pub fn calculate(
+        &self,
+        t: impl Time,
+        current: ProfileState<P>,
+        goal: ProfileState<P>,
+    ) -> ProfileState<P> {
+        let t = t.standard().value();
+        let timing = self.timing(current, goal);
+        if t < 0.0 {
+            return current;
+        }
+        if t >= timing.total_time {
+            return goal;
+        }
+        let (input, t, initial) = if t < timing.inflection_time {
+            (timing.input, t, current.raw())
+        } else {
+            (-timing.input, t - timing.total_time, goal.raw())
+        };
+        ProfileState::from_raw(
+            self.distance_from_time(t, input, initial),
+            self.velocity_from_time(t, input, initial.1),
+        )
+    }
+
+    /// The time the profile from `current` to `goal` takes
+    #[must_use]
+    pub fn total_time(&self, current: ProfileState<P>, goal: ProfileState<P>) -> Second {
+        Second::new(self.timing(current, goal).total_time)
+    }
+
+    /// The system `dv/dt = a * v + input`, `input` is `±max_acceleration`
+    fn a(&self) -> f64 {
+        -self.max_acceleration.into() / self.max_velocity.into()
+    }
+
+    fn velocity_from_time(&self, t: f64, input: f64, initial_velocity: f64) -> f64 {
+        let a = self.a();
+        (initial_velocity + input / a).mul_add((a * t).exp(), -input / a)
+    }
+
+    fn distance_from_time(&self, t: f64, input: f64, (position, velocity): (f64, f64)) -> f64 {
+        let a = self.a();
+        position + (velocity + input / a).mul_add((a * t).exp_m1(), -input * t) / a
+    }
+
+    fn time_from_velocity(&self, velocity: f64, input: f64, initial_velocity: f64) -> f64 {
+        let a = self.a();
+        (a.mul_add(velocity, input) / a.mul_add(initial_velocity, input)).ln() / a
+    }
+
+    fn distance_from_velocity(
+        &self,
+        velocity: f64,
+        input: f64,
+        (position, initial_velocity): (f64, f64),
+    ) -> f64 {
+        let a = self.a();
+        let log = (a.mul_add(velocity, input) / a.mul_add(initial_velocity, input)).ln();
+        (input / (a * a)).mul_add(-log, position + (velocity - initial_velocity) / a)
+    }
+
+    /// If the profile has to start by accelerating backwards
+    fn should_flip_input(&self, current: (f64, f64), goal: (f64, f64)) -> bool {
+        let (max_velocity, input): (f64, f64) =
+            (self.max_velocity.into(), self.max_acceleration.into());
+        let (velocity, (goal_position, goal_velocity)) = (current.1, goal);
+        let forward = self.distance_from_velocity(goal_velocity, input, current);
+        let reverse = self.distance_from_velocity(goal_velocity, -input, current);
+
+        if velocity >= max_velocity {
+            return goal_position < reverse;
+        }
+        if velocity <= -max_velocity {
+            return goal_position < forward;
+        }
+        let (moving_forward, ending_forward) = (velocity >= 0.0, goal_velocity >= 0.0);
+        let (past_forward, past_reverse) = (goal_position >= forward, goal_position >= reverse);
+        (moving_forward && !past_reverse)
+            || (ending_forward && !past_forward)
+            || (!past_forward && !past_reverse)
+    }
+
+    /// The velocity where accelerating from `current` meets braking into `goal`
+    fn inflection_velocity(&self, input: f64, current: (f64, f64), goal: (f64, f64)) -> f64 {
+        let a = self.a();
+        let position_delta = goal.0 - current.0;
+        let velocity_delta = goal.1 - current.1;
+        let scalar = a.mul_add(current.1, input) * a.mul_add(goal.1, -input);
+        let power = -a / input * a.mul_add(position_delta, -velocity_delta);
+        let c = scalar.mul_add(power.exp(), input * input);
+        if c <= 0.0 {
+            return 0.0;
+        }
+        input.signum() * c.sqrt() / a.abs()
+    }
+
+    fn timing(&self, current: ProfileState<P>, goal: ProfileState<P>) -> ExponentialTiming {
+        const EPSILON: f64 = 1e-9;
+        let (current, goal) = (current.raw(), goal.raw());
+        let max_velocity: f64 = self.max_velocity.into();
+        let input = if self.should_flip_input(current, goal) {
+            -self.max_acceleration.into()
+        } else {
+            self.max_acceleration.into()
+        };
+        if current == goal {
+            return ExponentialTiming {
+                input,
+                inflection_time: 0.0,
+                total_time: 0.0,
+            };
+        }
+
+        let inflection_velocity = self.inflection_velocity(input, current, goal);
+        let inflection_position = self.distance_from_velocity(inflection_velocity, -input, goal);
+
+        // the top speed is only reached asymptotically,
+        // so the profile cruises at it from just before it is reached
+        let inflection_time = if input
+            .signum()
+            .mul_add(max_velocity, -inflection_velocity)
+            .abs()
+            < EPSILON
+        {
+            let (time, position) = if (current.1 - inflection_velocity).abs() < EPSILON {
+                (0.0, current.0)
+            } else {
+                let solvable = if current.1.abs() > max_velocity {
+                    input.signum().mul_add(EPSILON, inflection_velocity)
+                } else {
+                    input.signum().mul_add(-EPSILON, inflection_velocity)
+                };
+                (
+                    self.time_from_velocity(solvable, input, current.1),
+                    self.distance_from_velocity(solvable, input, current),
+                )
+            };
+            time + input.signum() * (inflection_position - position) / max_velocity
+        } else {
+            self.time_from_velocity(inflection_velocity, input, current.1)
+        };
+        let braking_time = self.time_from_velocity(inflection_velocity, -input, goal.1);
+        ExponentialTiming {
+            input,
+            inflection_time,
+            total_time: inflection_time - braking_time,
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::time::Duration;
+
+use crate::math::{
+    clamp, deadband, inverse_lerp, lerp, ArmFeedforward, DebounceType, Debouncer,
+    ElevatorFeedforward, ExponentialProfile, LinearFilter, MedianFilter, PIDController,
+    ProfileState, SimpleMotorFeedforward, SlewRateLimiter, TrapezoidProfile,
+};
+use crate::time::Instant;
+use crate::units::{
+    angle::{Degree, Radian},
+    angular_acceleration::{DegreePerSecSqr, RadianPerSecSqr},
+    angular_velocity::{DegreePerSec, RotationPerSec},
+    length::Meter,
+    linear_acceleration::MetersPerSecSqr,
+    linear_velocity::{FeetPerSecond, MetersPerSecond},
+    temperature::{Celsius, CelsiusDelta, Fahrenheit, FahrenheitDelta},
+    time::{Millisecond, Second},
+    voltage::{Millivolt, Volt},
+};
+
+fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>) {
+    let (actual, expected) = (actual.into(), expected.into());
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[test]
+fn interpolation() {
+    assert_close(
+        clamp(
+            Degree::new(200.0),
+            Radian::new(0.0),
+            Radian::new(std::f64::consts::PI),
+        ),
+        180.0,
+    );
+    assert_eq!(clamp(Meter::new(0.5), 0.0, 1.0), Meter::new(0.5));
+    assert_eq!(clamp(Meter::new(-1.0), 0.0, 1.0), Meter::new(0.0));
+
+    assert_close(lerp(Meter::new(1.0), Meter::new(3.0), 0.5), 2.0);
+    assert_close(lerp(Meter::new(1.0), Meter::new(3.0), 1.5), 4.0);
+    assert_close(
+        inverse_lerp(Meter::new(1.0), Meter::new(3.0), Meter::new(2.5)),
+        0.75,
+    );
+    assert_close(
+        inverse_lerp(Meter::new(1.0), Meter::new(1.0), Meter::new(2.5)),
+        0.0,
+    );
+}
+
+#[test]
+fn point_unit_differences() {
+    // rates and bands of temperatures are deltas, they never pick up the 32 °F offset
+    let mut limiter = SlewRateLimiter::<Fahrenheit>::new(CelsiusDelta::new(2.0), Second::new(1.0));
+    limiter.reset(Fahrenheit::new(32.0));
+    assert_close(
+        limiter.calculate_dt(Celsius::new(100.0).into(), Second::new(1.0)),
+        35.6,
+    );
+
+    let max = FahrenheitDelta::new(100.0);
+    assert_close(
+        deadband(Fahrenheit::new(20.0), CelsiusDelta::new(0.0), max),
+        20.0,
+    );
+    assert_close(
+        deadband(Fahrenheit::new(1.5), CelsiusDelta::new(1.0), max),
+        0.0,
+    );
+}
+
+#[test]
+fn deadbands() {
+    let max = MetersPerSecond::new(1.0);
+    assert_close(deadband(MetersPerSecond::new(0.05), 0.1, max), 0.0);
+    assert_close(deadband(MetersPerSecond::new(-0.05), 0.1, max), 0.0);
+    assert_close(deadband(MetersPerSecond::new(0.55), 0.1, max), 0.5);
+    assert_close(deadband(MetersPerSecond::new(-1.0), 0.1, max), -1.0);
+    assert_close(
+        deadband(
+            MetersPerSecond::new(0.1),
+            FeetPerSecond::new(1.0),
+            MetersPerSecond::new(4.0),
+        ),
+        0.0,
+    );
+}
+
+#[test]
+fn slew_rate_limiter() {
+    let mut limiter = SlewRateLimiter::with_rates(
+        MetersPerSecond::new(2.0),
+        MetersPerSecond::new(-4.0),
+        Second::new(1.0),
+    );
+    assert_close(
+        limiter.calculate_dt(MetersPerSecond::new(1.0), Millisecond::new(100.0)),
+        0.2,
+    );
+    assert_close(
+        limiter.calculate_dt(MetersPerSecond::new(0.3), Millisecond::new(100.0)),
+        0.3,
+    );
+    assert_close(
+        limiter.calculate_dt(MetersPerSecond::new(-1.0), Millisecond::new(100.0)),
+        -0.1,
+    );
+    assert_close(limiter.last_value(), -0.1);
+    // time running backwards does not move the value
+    assert_close(
+        limiter.calculate_dt(MetersPerSecond::new(5.0), Millisecond::new(-100.0)),
+        -0.1,
+    );
+
+    let mut flipped = SlewRateLimiter::with_rates(
+        MetersPerSecond::new(2.0),
+        MetersPerSecond::new(4.0),
+        Second::new(1.0),
+    );
+    assert_close(
+        flipped.calculate_dt(MetersPerSecond::new(0.0), Millisecond::new(100.0)),
+        0.0,
+    );
+    assert_close(
+        flipped.calculate_dt(MetersPerSecond::new(-1.0), Millisecond::new(100.0)),
+        -0.4,
+    );
+
+    let mut unlimited = SlewRateLimiter::new(MetersPerSecond::new(1.0), Second::new(0.0));
+    assert_close(
+        unlimited.calculate_dt(MetersPerSecond::new(5.0), Millisecond::new(100.0)),
+        5.0,
+    );
+    assert_close(
+        unlimited.calculate_dt(MetersPerSecond::new(-5.0), Second::new(0.0)),
+        -5.0,
+    );
+
+    let start = Instant::now();
+    let mut limiter = SlewRateLimiter::new(Degree::new(90.0), Second::new(1.0));
+    assert_close(limiter.calculate_at(Degree::new(10.0), start), 10.0);
+    assert_close(
+        limiter.calculate_at(Degree::new(100.0), start + Duration::from_millis(500)),
+        55.0,
+    );
+    limiter.reset(Degree::new(0.0));
+    assert_close(
+        limiter.calculate_at(Degree::new(100.0), start + Duration::from_secs(10)),
+        100.0,
+    );
+}
+
+#[test]
+fn linear_filters() {
+    let mut average = LinearFilter::moving_average(3);
+    let outputs: Vec<f64> = [3.0, 6.0, 9.0, 12.0]
+        .into_iter()
+        .map(|input| average.calculate(Meter::new(input)).value())
+        .collect();
+    for (output, expected) in outputs.into_iter().zip([1.0, 3.0, 6.0, 9.0]) {
+        assert_close(output, expected);
+    }
+    assert_close(average.last_value(), 9.0);
+    average.reset();
+    assert_close(average.last_value(), 0.0);
+
+    let gain = (-0.02_f64 / 0.1).exp();
+    let mut low_pass = LinearFilter::single_pole_iir(Second::new(0.1), Millisecond::new(20.0));
+    assert_close(low_pass.calculate(Meter::new(1.0)), 1.0 - gain);
+    assert_close(low_pass.calculate(Meter::new(1.0)), 1.0 - gain * gain);
+
+    let mut high_pass = LinearFilter::high_pass(Second::new(0.1), Millisecond::new(20.0));
+    assert_close(high_pass.calculate(Meter::new(1.0)), gain);
+    assert_close(high_pass.calculate(Meter::new(1.0)), gain * gain);
+}
+
+#[test]
+fn median_filter() {
+    let mut filter = MedianFilter::new(4);
+    let outputs: Vec<f64> = [5.0, 1.0, 100.0, 3.0, 2.0]
+        .into_iter()
+        .map(|input| filter.calculate(Meter::new(input)).value())
+        .collect();
+    for (output, expected) in outputs.into_iter().zip([5.0, 3.0, 5.0, 4.0, 2.5]) {
+        assert_close(output, expected);
+    }
+}
+
+#[test]
+fn debouncer() {
+    let start = Instant::now();
+    let at = |millis| start + Duration::from_millis(millis);
+
+    let mut rising = Debouncer::new(Millisecond::new(100.0), DebounceType::Rising);
+    assert!(!rising.calculate_at(false, at(0)));
+    assert!(!rising.calculate_at(true, at(50)));
+    assert!(rising.calculate_at(true, at(100)));
+    assert!(!rising.calculate_at(false, at(110)));
+
+    let mut falling = Debouncer::new(Millisecond::new(100.0), DebounceType::Falling);
+    assert!(falling.calculate_at(true, at(0)));
+    assert!(falling.calculate_at(false, at(50)));
+    assert!(!falling.calculate_at(false, at(100)));
+
+    let mut both = Debouncer::new(Millisecond::new(100.0), DebounceType::Both);
+    assert!(!both.calculate_at(false, at(0)));
+    assert!(!both.calculate_at(true, at(50)));
+    assert!(both.calculate_at(true, at(150)));
+    assert!(both.calculate_at(false, at(200)));
+    assert!(!both.calculate_at(false, at(300)));
+}
+
+#[test]
+fn pid_controller() {
+    let mut pid = PIDController::<Meter, Volt>::new()
+        .with_p(Volt::new(2.0), Meter::new(1.0))
+        .with_i(Volt::new(1.0), Meter::new(1.0), Second::new(1.0))
+        .with_d(Volt::new(0.5), Meter::new(1.0), Second::new(1.0));
+    assert_eq!(pid.gains(), (2.0, 1.0, 0.5));
+    pid.set_setpoint(Meter::new(1.0));
+    pid.set_tolerance(Meter::new(0.1));
+
+    assert_close(
+        pid.calculate_dt(Meter::new(0.0), Millisecond::new(500.0)),
+        2.5,
+    );
+    assert_close(pid.error(), 1.0);
+    // p 1.0, i 0.75, d -0.5
+    assert_close(
+        pid.calculate_dt(Meter::new(0.5), Millisecond::new(500.0)),
+        1.25,
+    );
+    assert!(!pid.at_setpoint());
+    let _ = pid.calculate_dt(Meter::new(0.95), Millisecond::new(500.0));
+    assert!(pid.at_setpoint());
+
+    pid.reset();
+    pid.set_integrator_range(Volt::new(-0.25), Volt::new(0.25));
+    let start = Instant::now();
+    assert_close(pid.calculate_at(Meter::new(0.0), start), 2.0);
+    assert_close(
+        pid.calculate_at(Meter::new(0.0), start + Duration::from_secs(2)),
+        2.25,
+    );
+
+    let mut angle = PIDController::<Radian, Volt>::new().with_p(Volt::new(1.0), Degree::new(1.0));
+    angle.set_setpoint(Degree::new(-175.0));
+    assert_close(
+        angle.calculate_dt(Degree::new(175.0), Second::new(1.0)),
+        -350.0,
+    );
+    angle.enable_continuous_input(Degree::new(-180.0), Degree::new(180.0));
+    assert_close(
+        angle.calculate_dt(Degree::new(175.0), Second::new(1.0)),
+        10.0,
+    );
+}
+
+#[test]
+fn pid_point_units() {
+    // 1 V for every 10 °C of error is 1 V for every 18 °F
+    let mut pid =
+        PIDController::<Fahrenheit, Volt>::new().with_p(Volt::new(1.0), CelsiusDelta::new(10.0));
+    pid.set_setpoint(Celsius::new(10.0));
+    pid.set_tolerance(CelsiusDelta::new(1.0));
+    assert_close(
+        pid.calculate_dt(Fahrenheit::new(32.0), Second::new(0.02)),
+        1.0,
+    );
+    assert_close(pid.error(), FahrenheitDelta::new(18.0));
+    assert!(!pid.at_setpoint());
+    let _ = pid.calculate_dt(Fahrenheit::new(49.0), Second::new(0.02));
+    assert!(pid.at_setpoint());
+
+    let mut wrapped = PIDController::<Fahrenheit, Volt>::new().with_p(Volt::new(1.0), 1.0);
+    // 0 °C and 100 °C are the same point 180 °F apart
+    wrapped.enable_continuous_input(Celsius::new(0.0), Celsius::new(100.0));
+    wrapped.set_setpoint(Celsius::new(0.0));
+    assert_close(
+        wrapped.calculate_dt(Fahrenheit::new(202.0), Second::new(0.02)),
+        10.0,
+    );
+}
+
+#[test]
+fn feedforwards() {
+    let drive = SimpleMotorFeedforward::<Meter>::new(Volt::new(0.2))
+        .with_v(Volt::new(2.0), FeetPerSecond::new(3.0))
+        .with_a(Volt::new(0.5), MetersPerSecSqr::new(1.0));
+    assert_close(
+        drive.calculate(MetersPerSecond::new(-0.9144), MetersPerSecSqr::new(2.0)),
+        -1.2,
+    );
+    assert_close(
+        drive.calculate(MetersPerSecond::new(0.0), MetersPerSecSqr::new(0.0)),
+        0.0,
+    );
+
+    let arm = ArmFeedforward::new(Volt::new(0.1), Volt::new(1.0))
+        .with_v(Volt::new(1.0), RotationPerSec::new(1.0))
+        .with_a(Volt::new(0.1), RadianPerSecSqr::new(1.0));
+    assert_close(
+        arm.calculate(
+            Degree::new(0.0),
+            RotationPerSec::new(0.5),
+            RadianPerSecSqr::new(2.0),
+        ),
+        1.8,
+    );
+    assert_close(
+        arm.calculate(
+            Degree::new(90.0),
+            RotationPerSec::new(0.0),
+            RadianPerSecSqr::new(0.0),
+        ),
+        0.0,
+    );
+
+    let elevator = ElevatorFeedforward::new(Volt::new(0.1), Millivolt::new(600.0));
+    assert_close(
+        elevator.calculate(MetersPerSecond::new(-1.0), MetersPerSecSqr::new(0.0)),
+        0.5,
+    );
+}
+
+#[test]
+fn trapezoid_profile() {
+    let profile =
+        TrapezoidProfile::<Meter>::new(MetersPerSecond::new(1.0), MetersPerSecSqr::new(2.0));
+    let start = ProfileState::at_rest(Meter::new(0.0));
+    let goal = ProfileState::at_rest(Meter::new(2.0));
+    assert_close(profile.total_time(start, goal), 2.5);
+
+    let accelerating = profile.calculate(Second::new(0.25), start, goal);
+    assert_close(accelerating.position, 0.0625);
+    assert_close(accelerating.velocity, 0.5);
+    let cruising = profile.calculate(Millisecond::new(1000.0), start, goal);
+    assert_close(cruising.position, 0.75);
+    assert_close(cruising.velocity, 1.0);
+    let braking = profile.calculate(Second::new(2.25), start, goal);
+    assert_close(braking.position, 1.9375);
+    assert_close(braking.velocity, 0.5);
+    assert_eq!(profile.calculate(Second::new(3.0), start, goal), goal);
+
+    // too short to reach full speed, backwards and in degrees
+    let profile =
+        TrapezoidProfile::<Radian>::new(DegreePerSec::new(360.0), DegreePerSecSqr::new(90.0));
+    let start = ProfileState::at_rest(Degree::new(90.0));
+    let goal = ProfileState::at_rest(Degree::new(0.0));
+    assert_close(profile.total_time(start, goal), 2.0);
+    let middle = profile.calculate(Second::new(1.0), start, goal);
+    assert_close(Degree::from(middle.position), 45.0);
+    assert_close(DegreePerSec::from(middle.velocity), -90.0);
+
+    // profiles work in any position unit with velocity and acceleration relations
+    let profile =
+        TrapezoidProfile::<Degree>::new(DegreePerSec::new(90.0), DegreePerSecSqr::new(180.0));
+    let start = ProfileState::at_rest(Degree::new(0.0));
+    let goal = ProfileState::at_rest(Degree::new(180.0));
+    assert_close(profile.total_time(start, goal), 2.5);
+    let middle: ProfileState<Degree> = profile.calculate(Second::new(1.25), start, goal);
+    assert_close(middle.position, 90.0);
+    assert_close(middle.velocity, DegreePerSec::new(90.0));
+}
+
+#[test]
+fn exponential_profile() {
+    let (max_velocity, max_acceleration) = (4.0, 8.0);
+    let profile = ExponentialProfile::<Meter>::new(
+        MetersPerSecond::new(max_velocity),
+        MetersPerSecSqr::new(max_acceleration),
+    );
+
+    for (start, goal) in [(0.0, 0.5), (0.0, 10.0), (3.0, -2.0)] {
+        let start = ProfileState::at_rest(Meter::new(start));
+        let goal = ProfileState::at_rest(Meter::new(goal));
+        let total = profile.total_time(start, goal).value();
+        assert!(total > 0.0);
+
+        let dt = 1e-3;
+        let mut previous = start;
+        let times = (1..).map(|step| f64::from(step) * dt);
+        for t in times.take_while(|t| *t < total + dt) {
+            let state = profile.calculate(Second::new(t), start, goal);
+            let (velocity, previous_velocity) = (state.velocity.value(), previous.velocity.value());
+            assert!(velocity.abs() <= max_velocity + 1e-6);
+            // the motor model bounds the acceleration by how fast it is already going
+            let limit = max_acceleration * (1.0 + previous_velocity.abs() / max_velocity);
+            assert!(((velocity - previous_velocity) / dt).abs() <= limit + 1e-3);
+            let moved = state.position.value() - previous.position.value();
+            assert!(moved.abs() <= max_velocity.mul_add(dt, 1e-9));
+            previous = state;
+        }
+        assert_close(previous.position, goal.position);
+        assert_close(previous.velocity, 0.0);
+    }
+
+    let at_rest = ProfileState::at_rest(Meter::new(1.0));
+    assert_close(profile.total_time(at_rest, at_rest), 0.0);
+}
@@ -5,6 +5,11 @@
 mod test;
 
 mod prims;
+mod schema;
+
+pub use schema::{
+    FrcSchemaError, FrcStructField, FrcStructFieldType, FrcStructPrimitive, FrcStructSchema,
+};
 
 // use logos::Logos;
 
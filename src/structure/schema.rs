@@ -0,0 +1,271 @@
+//! A parser for the schema strings described by the
+//! [WPIlib struct spec](https://github.com/wpilibsuite/allwpilib/blob/main/wpiutil/doc/struct.adoc).
+
+use super::{FrcStructDesc, FrcStructDescDB};
+
+/// An error that occurs when parsing a structure schema
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FrcSchemaError {
+    #[error("Field declaration `{0}` is malformed")]
+    MalformedField(String),
+    #[error("Enum declaration `{0}` is malformed")]
+    MalformedEnum(String),
+    #[error("Type `{0}` is not a primitive and is not in the structure database")]
+    UnknownType(String),
+    #[error("Field `{0}` is declared more than once")]
+    DuplicateField(String),
+    #[error("Field `{0}` uses bit-fields which are not supported")]
+    UnsupportedBitField(String),
+    #[error("Field `{0}` is an enum but its type is not an integer")]
+    NonIntegerEnum(String),
+}
+
+/// A primitive type that can appear in a structure schema
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrcStructPrimitive {
+    Bool,
+    Char,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float32,
+    Float64,
+}
+
+impl FrcStructPrimitive {
+    /// Gets the primitive from its name in a schema, returns None if the name is not a primitive
+    #[must_use]
+    pub fn from_type_str(type_str: &str) -> Option<Self> {
+        match type_str {
+            "bool" => Some(Self::Bool),
+            "char" => Some(Self::Char),
+            "int8" => Some(Self::Int8),
+            "int16" => Some(Self::Int16),
+            "int32" => Some(Self::Int32),
+            "int64" => Some(Self::Int64),
+            "uint8" => Some(Self::UInt8),
+            "uint16" => Some(Self::UInt16),
+            "uint32" => Some(Self::UInt32),
+            "uint64" => Some(Self::UInt64),
+            "float" | "float32" => Some(Self::Float32),
+            "double" | "float64" => Some(Self::Float64),
+            _ => None,
+        }
+    }
+
+    /// The size of a single value of this primitive in bytes
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Bool | Self::Char | Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float32 => 4,
+            Self::Int64 | Self::UInt64 | Self::Float64 => 8,
+        }
+    }
+
+    /// Returns true if the primitive is a signed or unsigned integer
+    #[must_use]
+    pub const fn is_integer(self) -> bool {
+        matches!(
+            self,
+            Self::Int8
+                | Self::Int16
+                | Self::Int32
+                | Self::Int64
+                | Self::UInt8
+                | Self::UInt16
+                | Self::UInt32
+                | Self::UInt64
+        )
+    }
+}
+
+/// The type of a single field in a structure schema
+#[allow(missing_docs, variant_size_differences)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrcStructFieldType {
+    Primitive(FrcStructPrimitive),
+    Struct(&'static FrcStructDesc),
+}
+
+/// A single field of a structure schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrcStructField {
+    /// The name of the field
+    pub name: String,
+    /// The type of the field
+    pub field_type: FrcStructFieldType,
+    /// The length of the field if it is an array
+    pub array_len: Option<usize>,
+    /// The named values of the field if it is an enum, empty otherwise
+    pub enum_variants: Vec<(String, i64)>,
+}
+
+impl FrcStructField {
+    /// The size of the field in bytes, including every array element
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        let element_size = match self.field_type {
+            FrcStructFieldType::Primitive(prim) => prim.size(),
+            FrcStructFieldType::Struct(desc) => desc.size,
+        };
+        match self.array_len {
+            Some(len) => element_size * len,
+            None => element_size,
+        }
+    }
+
+    /// Gets the name of an enum variant from its value
+    #[must_use]
+    pub fn variant_name(&self, value: i64) -> Option<&str> {
+        self.enum_variants
+            .iter()
+            .find(|(_, variant_value)| *variant_value == value)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Gets the value of an enum variant from its name
+    #[must_use]
+    pub fn variant_value(&self, name: &str) -> Option<i64> {
+        self.enum_variants
+            .iter()
+            .find(|(variant_name, _)| variant_name == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// A parsed structure schema, nested structure types are resolved through the [``FrcStructDescDB``]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FrcStructSchema {
+    /// The fields of the structure in the order they are packed
+    pub fields: Vec<FrcStructField>,
+}
+
+impl FrcStructSchema {
+    /// Parses the schema of a structure description
+    ///
+    /// # Errors
+    /// Returns an error if the schema is malformed or references an unknown type
+    pub fn from_desc(desc: &FrcStructDesc) -> Result<Self, FrcSchemaError> {
+        Self::parse(&(desc.schema_supplier)())
+    }
+
+    /// Parses a schema string
+    ///
+    /// # Errors
+    /// Returns an error if the schema is malformed or references an unknown type
+    pub fn parse(schema: &str) -> Result<Self, FrcSchemaError> {
+        let mut fields: Vec<FrcStructField> = Vec::new();
+        for declaration in schema.split(';') {
+            let declaration = declaration.trim();
+            if declaration.is_empty() {
+                continue;
+            }
+            let field = parse_field(declaration)?;
+            if fields.iter().any(|other| other.name == field.name) {
+                return Err(FrcSchemaError::DuplicateField(field.name));
+            }
+            fields.push(field);
+        }
+        Ok(Self { fields })
+    }
+
+    /// The packed size of the structure in bytes
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.fields.iter().map(FrcStructField::size).sum()
+    }
+
+    /// Gets a field by name
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&FrcStructField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+fn parse_field(declaration: &str) -> Result<FrcStructField, FrcSchemaError> {
+    let malformed = || FrcSchemaError::MalformedField(declaration.to_owned());
+
+    let (enum_variants, rest) = if let Some(open) = declaration.find('{') {
+        let prefix = declaration[..open].trim();
+        if !(prefix.is_empty() || prefix == "enum") {
+            return Err(malformed());
+        }
+        let close = declaration.find('}').ok_or_else(malformed)?;
+        if close < open {
+            return Err(malformed());
+        }
+        (
+            parse_enum(&declaration[open + 1..close])?,
+            &declaration[close + 1..],
+        )
+    } else {
+        (Vec::new(), declaration)
+    };
+
+    let mut parts = rest.split_whitespace();
+    let (Some(type_str), Some(name_str), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(malformed());
+    };
+
+    // array lengths are allowed to be separated from the name by whitespace in the spec
+    // but every known producer places them directly after the name
+    let (name, array_len) = if let Some(open) = name_str.find('[') {
+        let len_str = name_str[open + 1..]
+            .strip_suffix(']')
+            .ok_or_else(malformed)?;
+        let len = len_str.trim().parse::<usize>().map_err(|_| malformed())?;
+        (&name_str[..open], Some(len))
+    } else {
+        (name_str, None)
+    };
+
+    if name.contains(':') {
+        return Err(FrcSchemaError::UnsupportedBitField(name.to_owned()));
+    }
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(malformed());
+    }
+
+    let field_type = match FrcStructPrimitive::from_type_str(type_str) {
+        Some(prim) => FrcStructFieldType::Primitive(prim),
+        None => FrcStructFieldType::Struct(
+            FrcStructDescDB::get(type_str)
+                .ok_or_else(|| FrcSchemaError::UnknownType(type_str.to_owned()))?,
+        ),
+    };
+
+    if !enum_variants.is_empty()
+        && !matches!(field_type, FrcStructFieldType::Primitive(prim) if prim.is_integer())
+    {
+        return Err(FrcSchemaError::NonIntegerEnum(name.to_owned()));
+    }
+
+    Ok(FrcStructField {
+        name: name.to_owned(),
+        field_type,
+        array_len,
+        enum_variants,
+    })
+}
+
+fn parse_enum(body: &str) -> Result<Vec<(String, i64)>, FrcSchemaError> {
+    let malformed = || FrcSchemaError::MalformedEnum(body.to_owned());
+    body.split(',')
+        .map(str::trim)
+        .filter(|variant| !variant.is_empty())
+        .map(|variant| {
+            let (name, value) = variant.split_once('=').ok_or_else(malformed)?;
+            let value = value.trim().parse::<i64>().map_err(|_| malformed())?;
+            Ok((name.trim().to_owned(), value))
+        })
+        .collect()
+}
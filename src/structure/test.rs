@@ -80,50 +80,80 @@ fn test_structures() {
     }
 }
 
-// #[test]
-// fn test_schema() {
-//     const SCHEMA: &str = "enum {a=1, b=2} int8 val[3]";
-//     let fields = parse_schema_toplevel(SCHEMA);
-//     assert_eq!(fields.len(), 1);
-//     assert_eq!(
-//         fields[0],
-//         ("val".to_owned(), 0, StructureFieldTypes::Int8(3))
-//     );
-// }
-
-// #[test]
-// fn test_schema_advanced() {
-//     const SCHEMA: &str = "Rotation2d rot; Translation2d trans;";
-//     FrcStructDescDB::add(FrcStructDesc {
-//         schema_supplier: || "double value".to_owned(),
-//         type_str: "Rotation2d",
-//         size: 8,
-//     });
-//     FrcStructDescDB::add(FrcStructDesc {
-//         schema_supplier: || "double x; double y".to_owned(),
-//         type_str: "Translation2d",
-//         size: 16,
-//     });
-//     let fields = parse_schema_toplevel(SCHEMA);
-//     assert_eq!(fields.len(), 3);
-//     assert_eq!(
-//         fields,
-//         vec![
-//             (
-//                 "rot.value".to_owned(),
-//                 0usize,
-//                 StructureFieldTypes::Float64(1)
-//             ),
-//             (
-//                 "trans.x".to_owned(),
-//                 8usize,
-//                 StructureFieldTypes::Float64(1)
-//             ),
-//             (
-//                 "trans.y".to_owned(),
-//                 16usize,
-//                 StructureFieldTypes::Float64(1)
-//             )
-//         ]
-//     );
-// }
+#[test]
+fn test_schema() {
+    let schema = FrcStructSchema::parse("enum {a=1, b=2} int8 val[3]").expect("Failed to parse");
+    assert_eq!(schema.fields.len(), 1);
+    let field = &schema.fields[0];
+    assert_eq!(field.name, "val");
+    assert_eq!(
+        field.field_type,
+        FrcStructFieldType::Primitive(FrcStructPrimitive::Int8)
+    );
+    assert_eq!(field.array_len, Some(3));
+    assert_eq!(field.variant_name(2), Some("b"));
+    assert_eq!(field.variant_value("a"), Some(1));
+    assert_eq!(schema.size(), 3);
+
+    assert_eq!(
+        FrcStructSchema::parse("bool a; float b; double c;").map(|s| s.size()),
+        Ok(13)
+    );
+    assert_eq!(
+        FrcStructSchema::parse("int8 a; int16 a"),
+        Err(FrcSchemaError::DuplicateField("a".to_owned()))
+    );
+    assert_eq!(
+        FrcStructSchema::parse("int8 a:4"),
+        Err(FrcSchemaError::UnsupportedBitField("a:4".to_owned()))
+    );
+    assert_eq!(
+        FrcStructSchema::parse("enum {a=1} double a"),
+        Err(FrcSchemaError::NonIntegerEnum("a".to_owned()))
+    );
+    assert_eq!(
+        FrcStructSchema::parse("NotAStruct a"),
+        Err(FrcSchemaError::UnknownType("NotAStruct".to_owned()))
+    );
+}
+
+#[test]
+fn test_schema_nested() {
+    FrcStructDescDB::add(FrcStructDesc {
+        schema_supplier: || "double value".to_owned(),
+        type_str: "SchemaTestRotation",
+        size: 8,
+    });
+    FrcStructDescDB::add(FrcStructDesc {
+        schema_supplier: || "double x; double y".to_owned(),
+        type_str: "SchemaTestTranslation",
+        size: 16,
+    });
+    let schema = FrcStructSchema::parse("SchemaTestRotation rot; SchemaTestTranslation trans[2];")
+        .expect("Failed to parse");
+    assert_eq!(schema.fields.len(), 2);
+    assert!(matches!(
+        schema.fields[0].field_type,
+        FrcStructFieldType::Struct(desc) if desc.type_str == "SchemaTestRotation"
+    ));
+    assert_eq!(schema.fields[1].array_len, Some(2));
+    assert_eq!(schema.size(), 40);
+}
+
+#[test]
+#[cfg(feature = "value-union")]
+fn test_derived_schema() {
+    use crate as frclib_core;
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure)]
+    struct DerivedSchema {
+        flag: bool,
+        value: f64,
+        arr: [i16; 3],
+    }
+
+    let schema = FrcStructSchema::from_desc(&DerivedSchema::DESCRIPTION).expect("Failed to parse");
+    assert_eq!(schema.size(), DerivedSchema::SIZE);
+    let names: Vec<_> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["flag", "value", "arr"]);
+}
@@ -1,19 +1,116 @@
 use crate::units::angular_acceleration::{
     DegreePerSecSqr, RadianPerSecSqr, RotationPerMinSqr, RotationPerSecSqr,
 };
+use crate::units::angular_jerk::{DegreePerSecCubed, RadianPerSecCubed, RotationPerSecCubed};
 use crate::units::angular_velocity::{DegreePerSec, RadianPerSec, RotationPerMin, RotationPerSec};
+use crate::units::quantity::dims;
 use crate::units::time::{Minute, Second};
-use crate::{unit, unit_conversion, unit_dim_analysis, unit_family};
+use crate::{unit, unit_dim_analysis, unit_family};
 
-unit!(Degree | Degrees | Deg | Degs: float);
-unit!(Radian | Radians | Rad | Rads: float);
-unit!(Rotation | Rotations | Rot | Rots: float);
+unit!(Degree | Degrees | Deg | Degs: angle = "°", "deg");
+unit!(Radian | Radians | Rad | Rads: float = "rad");
+unit!(Rotation | Rotations | Rot | Rots: angle = "rot", "rev");
 
-unit_conversion!(Degree(float) <-> Radian(float)   ~ |x| x.to_radians());
-unit_conversion!(Degree(float) <-> Rotation(float) ~ ratio 1.0/360.0);
-unit_conversion!(Radian(float) <-> Rotation(float) ~ |x| x / (std::f64::consts::PI * 2.0));
+unit_family!(Angle(Radian) in dims::Angle:
+    Degree = std::f64::consts::PI / 180.0,
+    Rotation = std::f64::consts::TAU
+);
 
-unit_family!(Angle(Radian): Degree, Rotation);
+/// Wrapping and trigonometry for every [`Angle`] unit.
+pub trait AngleExt: Angle {
+    /// Wraps this angle into `[-π, π)`, or `[-180, 180)` for [`Degree`].
+    #[must_use]
+    fn wrap(self) -> Self
+    where
+        Self: Into<f64> + From<f64>,
+    {
+        let turn = full_turn::<Self>();
+        let half = turn / 2.0;
+        let wrapped = (Into::<f64>::into(self) + half).rem_euclid(turn) - half;
+        <Self as From<f64>>::from(if wrapped < half { wrapped } else { -half })
+    }
+
+    /// Wraps this angle into `[0, 2π)`, or `[0, 360)` for [`Degree`].
+    #[must_use]
+    fn wrap_positive(self) -> Self
+    where
+        Self: Into<f64> + From<f64>,
+    {
+        let turn = full_turn::<Self>();
+        let wrapped = Into::<f64>::into(self).rem_euclid(turn);
+        <Self as From<f64>>::from(if wrapped < turn { wrapped } else { 0.0 })
+    }
+
+    /// The smallest signed angle that rotates this angle onto `target`,
+    /// counter-clockwise positive and in `[-π, π)`.
+    #[must_use]
+    fn shortest_difference(self, target: impl Angle) -> Self
+    where
+        Self: Into<f64> + From<f64>,
+    {
+        let target: Self = target.conv();
+        <Self as From<f64>>::from(Into::<f64>::into(target) - Into::<f64>::into(self)).wrap()
+    }
+
+    /// The angle equivalent to this one that is closest to `reference`,
+    /// this removes the jump when an angle crosses its wrapping point.
+    #[must_use]
+    fn unwrap_near(self, reference: impl Angle) -> Self
+    where
+        Self: Into<f64> + From<f64>,
+    {
+        let reference: Self = reference.conv();
+        <Self as From<f64>>::from(
+            Into::<f64>::into(reference) + Into::<f64>::into(reference.shortest_difference(self)),
+        )
+    }
+
+    /// The sine of this angle.
+    fn sin(self) -> f64 {
+        self.standard().0.sin()
+    }
+
+    /// The cosine of this angle.
+    fn cos(self) -> f64 {
+        self.standard().0.cos()
+    }
+
+    /// The tangent of this angle.
+    fn tan(self) -> f64 {
+        self.standard().0.tan()
+    }
+
+    /// The angle of the point `(x, y)` from the positive x axis in `(-π, π]`,
+    /// both components must be in the same unit.
+    fn atan2<T: Into<f64>>(y: T, x: T) -> Self {
+        Self::from(Radian(y.into().atan2(x.into())))
+    }
+}
+impl<T: Angle> AngleExt for T {}
+
+/// One full turn in the unit `A`, this goes through [`Rotation`] so the scale is exact.
+fn full_turn<A: Angle + Into<f64>>() -> f64 {
+    Into::<f64>::into(A::from(Rotation(1.0).standard()))
+}
+
+/// Unwraps a sequence of angle samples so consecutive samples never jump by more than half a turn.
+///
+/// This is useful for continuous mechanisms like turrets where a wrapped sensor reading
+/// needs to be tracked across multiple rotations.
+pub fn unwrap_angles<A>(samples: impl IntoIterator<Item = A>) -> Vec<A>
+where
+    A: AngleExt + Into<f64> + From<f64>,
+{
+    let mut unwrapped: Vec<A> = Vec::new();
+    for sample in samples {
+        let sample = match unwrapped.last() {
+            Some(previous) => sample.unwrap_near(*previous),
+            None => sample,
+        };
+        unwrapped.push(sample);
+    }
+    unwrapped
+}
 
 unit_dim_analysis!(DegreePerSec * Second = Degree);
 unit_dim_analysis!(RadianPerSec * Second = Radian);
@@ -24,3 +121,7 @@ unit_dim_analysis!(DegreePerSecSqr * Second = DegreePerSec);
 unit_dim_analysis!(RadianPerSecSqr * Second = RadianPerSec);
 unit_dim_analysis!(RotationPerSecSqr * Second = RotationPerSec);
 unit_dim_analysis!(RotationPerMinSqr * Minute = RotationPerMin);
+
+unit_dim_analysis!(DegreePerSecCubed * Second = DegreePerSecSqr);
+unit_dim_analysis!(RadianPerSecCubed * Second = RadianPerSecSqr);
+unit_dim_analysis!(RotationPerSecCubed * Second = RotationPerSecSqr);
@@ -0,0 +1,11 @@
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+unit!(DegreePerSecCubed | DegreesPerSecCubed | DegsPerSecCubed: float = "°/s³", "deg/s^3");
+unit!(RadianPerSecCubed | RadiansPerSecCubed | RadsPerSecCubed: float = "rad/s³", "rad/s^3");
+unit!(RotationPerSecCubed | RotationsPerSecCubed | RotsPerSecCubed: float = "rot/s³", "rot/s^3");
+
+unit_family!(AngleJerk(RadianPerSecCubed) in dims::AngularJerk:
+    DegreePerSecCubed = std::f64::consts::PI / 180.0,
+    RotationPerSecCubed = std::f64::consts::TAU
+);
@@ -0,0 +1,18 @@
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+/// One square foot in square meters
+pub(crate) const SQUARE_METERS_PER_SQUARE_FOOT: f64 = 0.3048 * 0.3048;
+/// One square inch in square meters
+pub(crate) const SQUARE_METERS_PER_SQUARE_INCH: f64 = 0.0254 * 0.0254;
+
+unit!(SquareMeter | SquareMeters: float = "m²", "m^2");
+unit!(SquareCentimeter | SquareCentimeters: float = "cm²", "cm^2");
+unit!(SquareFoot | SquareFeet: float = "ft²", "ft^2");
+unit!(SquareInch | SquareInches: float = "in²", "in^2");
+
+unit_family!(Area(SquareMeter) in dims::Area:
+    SquareCentimeter = 0.0001,
+    SquareFoot = SQUARE_METERS_PER_SQUARE_FOOT,
+    SquareInch = SQUARE_METERS_PER_SQUARE_INCH
+);
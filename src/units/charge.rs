@@ -0,0 +1,14 @@
+use crate::units::current::{Amp, Milliamp};
+use crate::units::quantity::dims;
+use crate::units::time::{Hour, Second};
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(Coulomb | Coulombs: float = "C");
+unit!(AmpHour | AmpHours: float = "Ah");
+unit!(MilliampHour | MilliampHours: float = "mAh");
+
+unit_family!(Charge(Coulomb) in dims::Charge: AmpHour = 3600.0, MilliampHour = 3.6);
+
+unit_dim_analysis!(Amp * Second = Coulomb);
+unit_dim_analysis!(Amp * Hour = AmpHour);
+unit_dim_analysis!(Milliamp * Hour = MilliampHour);
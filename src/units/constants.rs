@@ -0,0 +1,40 @@
+//! Typed physical and geometric constants.
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::constants::{FULL_ROTATION, STANDARD_GRAVITY};
+//! use frclib_core::units::time::Second;
+//!
+//! // speed gained falling for half a second
+//! assert!((STANDARD_GRAVITY * Second::new(0.5)).value() > 4.9);
+//! assert_eq!(FULL_ROTATION.value(), std::f64::consts::TAU);
+//! ```
+
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use super::angle::Radian;
+use super::linear_acceleration::MetersPerSecSqr;
+use super::linear_velocity::MetersPerSecond;
+use super::pressure::Pascal;
+use super::temperature::Kelvin;
+
+/// The standard acceleration of gravity at the earth's surface, exact by definition
+pub const STANDARD_GRAVITY: MetersPerSecSqr = MetersPerSecSqr::new(9.806_65);
+
+/// The speed of sound in dry air at 20 °C
+pub const SPEED_OF_SOUND: MetersPerSecond = MetersPerSecond::new(343.0);
+
+/// The standard atmospheric pressure at sea level
+pub const STANDARD_ATMOSPHERE: Pascal = Pascal::new(101_325.0);
+
+/// The lowest possible temperature
+pub const ABSOLUTE_ZERO: Kelvin = Kelvin::new(0.0);
+
+/// One full rotation, `2π` radians
+pub const FULL_ROTATION: Radian = Radian::new(TAU);
+
+/// Half a rotation, `π` radians
+pub const HALF_ROTATION: Radian = Radian::new(PI);
+
+/// A quarter rotation, `π/2` radians
+pub const QUARTER_ROTATION: Radian = Radian::new(FRAC_PI_2);
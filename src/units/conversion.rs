@@ -0,0 +1,95 @@
+use super::quantity::rescale;
+
+/// How a unit relates to the standard unit of its family,
+/// a value of the unit is `value * scale + offset` in the standard unit.
+///
+/// Most units are a pure scale of their standard unit, [`From<f64>`] creates one of those,
+/// [`From<(f64, f64)>`] creates an affine map from a `(scale, offset)` pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitMap {
+    /// The size of one of this unit in the standard unit
+    pub scale: f64,
+    /// The standard unit value of zero of this unit
+    pub offset: f64,
+}
+
+impl UnitMap {
+    /// The map of the standard unit to itself
+    pub const IDENTITY: Self = Self::linear(1.0);
+
+    /// A unit that is `scale` of the standard unit
+    #[must_use]
+    pub const fn linear(scale: f64) -> Self {
+        Self { scale, offset: 0.0 }
+    }
+
+    /// A unit that is `scale` of the standard unit with its zero at `offset`
+    #[must_use]
+    pub const fn affine(scale: f64, offset: f64) -> Self {
+        Self { scale, offset }
+    }
+
+    /// Converts a value of this unit to the standard unit
+    #[must_use]
+    #[inline]
+    pub fn to_standard(self, value: f64) -> f64 {
+        value.mul_add(self.scale, self.offset)
+    }
+
+    /// Converts a value of the standard unit to this unit
+    #[must_use]
+    #[inline]
+    pub fn from_standard(self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+
+    /// Converts a value between two units of the same family.
+    ///
+    /// Pure scales go through [`rescale`] so they match the [`Quantity`](super::quantity::Quantity) conversions exactly.
+    #[must_use]
+    #[inline]
+    pub fn convert(value: f64, from: Self, to: Self) -> f64 {
+        #[allow(clippy::float_cmp)]
+        if from.offset == 0.0 && to.offset == 0.0 {
+            rescale(value, from.scale, to.scale)
+        } else {
+            to.from_standard(from.to_standard(value))
+        }
+    }
+
+    /// The map of applying `self` and then `next`
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        Self::affine(
+            self.scale * next.scale,
+            self.offset.mul_add(next.scale, next.offset),
+        )
+    }
+
+    /// The map that undoes `self`
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self::affine(self.scale.recip(), -self.offset / self.scale)
+    }
+}
+
+impl From<f64> for UnitMap {
+    fn from(scale: f64) -> Self {
+        Self::linear(scale)
+    }
+}
+
+impl From<(f64, f64)> for UnitMap {
+    fn from((scale, offset): (f64, f64)) -> Self {
+        Self::affine(scale, offset)
+    }
+}
+
+/// A unit whose conversions are generated from its [`UnitMap`] to the standard unit of its family.
+///
+/// Implemented by [`unit_family!`](crate::unit_family) for every unit declared with a map
+/// and by [`unit_member!`](crate::unit_member) for units added to an existing family.
+pub trait FamilyUnit: Copy {
+    /// How this unit relates to the standard unit of its family
+    fn unit_map() -> UnitMap;
+}
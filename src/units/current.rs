@@ -0,0 +1,7 @@
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+unit!(Amp | Amps | Ampere | Amperes: float = "A");
+unit!(Milliamp | Milliamps: float = "mA");
+
+unit_family!(Current(Amp) in dims::Current: Milliamp = 0.001; scale: Milliamp, Amp);
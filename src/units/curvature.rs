@@ -0,0 +1,22 @@
+use crate::units::angle::{Degree, Radian};
+use crate::units::angular_velocity::{DegreePerSec, RadianPerSec};
+use crate::units::length::{Foot, Meter};
+use crate::units::linear_velocity::{FeetPerSecond, MetersPerSecond};
+use crate::units::quantity::dims;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(RadianPerMeter | RadiansPerMeter | PerMeter: float = "rad/m");
+unit!(DegreePerMeter | DegreesPerMeter: float = "°/m", "deg/m");
+unit!(RadianPerFoot | RadiansPerFoot | PerFoot: float = "rad/ft");
+
+unit_family!(Curvature(RadianPerMeter) in dims::Curvature:
+    DegreePerMeter = std::f64::consts::PI / 180.0,
+    RadianPerFoot = 1.0 / 0.3048
+);
+
+unit_dim_analysis!(RadianPerMeter * Meter = Radian);
+unit_dim_analysis!(DegreePerMeter * Meter = Degree);
+unit_dim_analysis!(RadianPerFoot * Foot = Radian);
+unit_dim_analysis!(RadianPerMeter * MetersPerSecond = RadianPerSec);
+unit_dim_analysis!(DegreePerMeter * MetersPerSecond = DegreePerSec);
+unit_dim_analysis!(RadianPerFoot * FeetPerSecond = RadianPerSec);
@@ -1,50 +1,53 @@
 use crate::units::data_rate::{
-    BytesPerSecond, GigabytesPerHour, KilobytesPerSecond, MegabytesPerSecond,
+    BitsPerSecond, BytesPerSecond, GigabitsPerSecond, GigabytesPerHour, KibibytesPerSecond,
+    KilobitsPerSecond, KilobytesPerSecond, MebibytesPerSecond, MegabitsPerSecond,
+    MegabytesPerSecond,
 };
 use crate::units::time::Second;
-use crate::{unit, unit_conversion, unit_dim_analysis, unit_family};
+use crate::{unit, unit_dim_analysis, unit_family};
 
 use super::time::Hour;
 
-unit!(Byte: float);
-unit!(Kilobyte: float);
-unit!(Megabyte: float);
-unit!(Gigabyte: float);
-
-unit_conversion!(Byte(float) <-> Kilobyte(float) ~ byte_to_kilobyte);
-unit_conversion!(Byte(float) <-> Megabyte(float) ~ byte_to_megabyte);
-unit_conversion!(Byte(float) <-> Gigabyte(float) ~ byte_to_gigabyte);
-unit_conversion!(Kilobyte(float) <-> Megabyte(float) ~ kilobyte_to_megabyte);
-unit_conversion!(Kilobyte(float) <-> Gigabyte(float) ~ kilobyte_to_gigabyte);
-unit_conversion!(Megabyte(float) <-> Gigabyte(float) ~ megabyte_to_gigabyte);
-
-unit_family!(Data(Byte): Kilobyte, Megabyte, Gigabyte);
+unit!(Byte: float = "B");
+unit!(Kilobyte: float = "kB", "KB");
+unit!(Megabyte: float = "MB");
+unit!(Gigabyte: float = "GB");
+unit!(Terabyte: float = "TB");
+unit!(Kibibyte: float = "KiB");
+unit!(Mebibyte: float = "MiB");
+unit!(Gibibyte: float = "GiB");
+unit!(Tebibyte: float = "TiB");
+unit!(Bit: float = "bit");
+unit!(Kilobit: float = "kbit");
+unit!(Megabit: float = "Mbit");
+unit!(Gigabit: float = "Gbit");
+
+// decimal prefixes, then binary prefixes, then bits which are an eighth of a byte
+unit_family!(Data(Byte):
+    Kilobyte = 1000.0,
+    Megabyte = 1_000_000.0,
+    Gigabyte = 1_000_000_000.0,
+    Terabyte = 1_000_000_000_000.0,
+    Kibibyte = 1024.0,
+    Mebibyte = 1_048_576.0,
+    Gibibyte = 1_073_741_824.0,
+    Tebibyte = 1_099_511_627_776.0,
+    Bit = 0.125,
+    Kilobit = 125.0,
+    Megabit = 125_000.0,
+    Gigabit = 125_000_000.0;
+    scale: Byte, Kilobyte, Megabyte, Gigabyte, Terabyte
+        | Kibibyte, Mebibyte, Gibibyte, Tebibyte
+        | Bit, Kilobit, Megabit, Gigabit
+);
 
 unit_dim_analysis!(BytesPerSecond * Second = Byte);
 unit_dim_analysis!(KilobytesPerSecond * Second = Kilobyte);
 unit_dim_analysis!(MegabytesPerSecond * Second = Megabyte);
 unit_dim_analysis!(GigabytesPerHour * Hour = Gigabyte);
-
-fn byte_to_kilobyte(byte: f64) -> f64 {
-    byte / 1000.0
-}
-
-fn byte_to_megabyte(byte: f64) -> f64 {
-    byte / 1_000_000.0
-}
-
-fn byte_to_gigabyte(byte: f64) -> f64 {
-    byte / 1_000_000_000.0
-}
-
-fn kilobyte_to_megabyte(kilobyte: f64) -> f64 {
-    kilobyte / 1000.0
-}
-
-fn kilobyte_to_gigabyte(kilobyte: f64) -> f64 {
-    kilobyte / 1_000_000.0
-}
-
-fn megabyte_to_gigabyte(megabyte: f64) -> f64 {
-    megabyte / 1000.0
-}
+unit_dim_analysis!(KibibytesPerSecond * Second = Kibibyte);
+unit_dim_analysis!(MebibytesPerSecond * Second = Mebibyte);
+unit_dim_analysis!(BitsPerSecond * Second = Bit);
+unit_dim_analysis!(KilobitsPerSecond * Second = Kilobit);
+unit_dim_analysis!(MegabitsPerSecond * Second = Megabit);
+unit_dim_analysis!(GigabitsPerSecond * Second = Gigabit);
@@ -1,18 +1,32 @@
-use crate::units::time::Second;
-use crate::{unit, unit_conversion, unit_dim_analysis};
+use crate::units::charge::Coulomb;
+use crate::units::power::Kilowatt;
+use crate::units::quantity::dims;
+use crate::units::time::{Hour, Second};
+use crate::{unit, unit_dim_analysis, unit_family};
 
-unit!(Joule: float);
-unit!(Volt: float);
-unit!(Amp: float);
-unit!(Watt: float);
-unit!(WattHour: float);
-unit!(Ohm: float);
+// the electrical units used to live in this module
+#[deprecated(since = "0.2.5", note = "moved to `units::current::Amp`")]
+pub use crate::units::current::Amp;
+#[deprecated(since = "0.2.5", note = "moved to `units::power::Watt`")]
+pub use crate::units::power::Watt;
+#[deprecated(since = "0.2.5", note = "moved to `units::resistance::Ohm`")]
+pub use crate::units::resistance::Ohm;
+#[deprecated(since = "0.2.5", note = "moved to `units::voltage::Volt`")]
+pub use crate::units::voltage::Volt;
 
-unit_conversion!(Joule(float) <-> WattHour(float) ~ joule_to_watthour);
+unit!(Joule | Joules: float = "J");
+unit!(Kilojoule | Kilojoules: float = "kJ");
+unit!(WattHour | WattHours: float = "Wh");
+unit!(KilowattHour | KilowattHours: float = "kWh");
 
-fn joule_to_watthour(joule: f64) -> f64 {
-    joule / 3600.0
-}
+unit_family!(Energy(Joule) in dims::Energy:
+    Kilojoule = 1000.0,
+    WattHour = 3600.0,
+    KilowattHour = 3_600_000.0;
+    scale: Joule, Kilojoule
+);
 
-unit_dim_analysis!(Volt * Ohm = Watt);
 unit_dim_analysis!(Watt * Second = Joule);
+unit_dim_analysis!(Watt * Hour = WattHour);
+unit_dim_analysis!(Kilowatt * Hour = KilowattHour);
+unit_dim_analysis!(Volt * Coulomb = Joule);
@@ -0,0 +1,26 @@
+use crate::units::area::{SquareInch, SquareMeter};
+use crate::units::constants::STANDARD_GRAVITY;
+use crate::units::linear_acceleration::MetersPerSecSqr;
+use crate::units::mass::{Kilogram, KILOGRAMS_PER_POUND};
+use crate::units::pressure::{Pascal, Psi};
+use crate::units::quantity::dims;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+/// One pound-force in newtons
+pub(crate) const NEWTONS_PER_POUND_FORCE: f64 = KILOGRAMS_PER_POUND * STANDARD_GRAVITY.value();
+
+unit!(Newton | Newtons: float = "N");
+unit!(Kilonewton | Kilonewtons: float = "kN");
+unit!(PoundForce | PoundsForce | Lbf: float = "lbf");
+unit!(KilogramForce | KilogramsForce | Kgf: float = "kgf");
+
+unit_family!(Force(Newton) in dims::Force:
+    Kilonewton = 1000.0,
+    PoundForce = NEWTONS_PER_POUND_FORCE,
+    KilogramForce = STANDARD_GRAVITY.value();
+    scale: Newton, Kilonewton
+);
+
+unit_dim_analysis!(Kilogram * MetersPerSecSqr = Newton);
+unit_dim_analysis!(Pascal * SquareMeter = Newton);
+unit_dim_analysis!(Psi * SquareInch = PoundForce);
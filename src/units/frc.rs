@@ -0,0 +1,214 @@
+//! Unit-typed conversions for common FRC mechanisms.
+//!
+//! Wheels, gear reductions and encoders are usually handled with bare `f64` multipliers
+//! like `ticks / 2048.0 * 2.0 * PI * radius / 6.75`, which are easy to get wrong.
+//! These types keep both sides of the conversion in the unit system.
+//! [`Wheel`] and [`GearRatio`] are also [`Mechanism`](super::mechanism::Mechanism)s
+//! that can be chained with other transforms.
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::angle::Rotation;
+//! use frclib_core::units::frc::{Encoder, GearRatio, Wheel};
+//! use frclib_core::units::length::{Inch, Meter};
+//!
+//! let encoder = Encoder::new(2048.0);
+//! let gearing = GearRatio::new(6.75);
+//! let wheel = Wheel::new(Inch::new(4.0));
+//!
+//! let motor = encoder.angle(2048.0 * 6.75);
+//! let traveled = wheel.distance(gearing.output(motor));
+//! assert!((traveled.value() - Meter::from(Inch::new(4.0)).value() * std::f64::consts::PI).abs() < 1e-12);
+//! assert!((gearing.input(Rotation::new(1.0)).value() - 6.75).abs() < 1e-12);
+//! ```
+
+use super::angle::{Angle, Rotation};
+use super::angular_acceleration::AngleAccel;
+use super::angular_velocity::{AngleVel, RotationPerSec};
+use super::length::{Distance, Meter};
+use super::linear_acceleration::LinearAcceleration;
+use super::linear_velocity::LinearVelocity;
+use super::mechanism::{Mechanism, Radius};
+use super::time::Time;
+use super::torque::Torque;
+
+/// A wheel that converts its rotation to distance traveled along the ground.
+///
+/// This is a [`Radius`] measured by its diameter, its ground speed and acceleration
+/// come from [`Mechanism::velocity`] and [`Mechanism::acceleration`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wheel(Radius);
+
+impl Wheel {
+    /// Creates a wheel with the given diameter
+    #[must_use]
+    pub fn new(diameter: impl Distance) -> Self {
+        Self(Radius::new(diameter.standard() / 2.0))
+    }
+
+    /// Creates a wheel with the given radius
+    #[must_use]
+    pub fn from_radius(radius: impl Distance) -> Self {
+        Self(Radius::new(radius))
+    }
+
+    /// The radius of the wheel
+    #[must_use]
+    pub const fn radius(self) -> Meter {
+        self.0.length()
+    }
+
+    /// The distance the wheel travels in one rotation
+    #[must_use]
+    pub fn circumference(self) -> Meter {
+        self.distance(Rotation::new(1.0))
+    }
+
+    /// The distance traveled by the wheel turning `angle`
+    #[must_use]
+    pub fn distance(self, angle: impl Angle) -> Meter {
+        self.position(angle.standard())
+    }
+
+    /// The angle the wheel turns to travel `distance`
+    #[must_use]
+    pub fn angle<A: Angle>(self, distance: impl Distance) -> A {
+        A::from(self.inverse().position(distance.standard()))
+    }
+
+    /// The angular velocity of the wheel moving at `velocity` along the ground
+    #[must_use]
+    pub fn angular_velocity<V: AngleVel>(self, velocity: impl LinearVelocity) -> V {
+        V::from(self.inverse().velocity(velocity.standard()))
+    }
+
+    /// The angular acceleration of the wheel accelerating at `acceleration` along the ground
+    #[must_use]
+    pub fn angular_acceleration<A: AngleAccel>(self, acceleration: impl LinearAcceleration) -> A {
+        A::from(self.inverse().acceleration(acceleration.standard()))
+    }
+}
+
+/// A gear reduction between a driving input and a driven output.
+///
+/// The reduction is the number of input rotations per output rotation,
+/// a `GearRatio::new(6.75)` turns the output once every 6.75 turns of the input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GearRatio {
+    reduction: f64,
+}
+
+impl GearRatio {
+    /// Creates a gear ratio with `reduction` input rotations per output rotation
+    #[must_use]
+    pub const fn new(reduction: f64) -> Self {
+        Self { reduction }
+    }
+
+    /// Creates a gear ratio from the teeth of the driving and the driven gear
+    #[must_use]
+    pub fn from_teeth(driving: u32, driven: u32) -> Self {
+        Self::new(f64::from(driven) / f64::from(driving))
+    }
+
+    /// The number of input rotations per output rotation
+    #[must_use]
+    pub const fn reduction(self) -> f64 {
+        self.reduction
+    }
+
+    /// The ratio of `self` followed by another stage
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        Self::new(self.reduction * next.reduction)
+    }
+
+    /// The same gearing driven from the output side
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self::new(self.reduction.recip())
+    }
+
+    /// The output angle for an input angle
+    #[must_use]
+    pub fn output<A: Angle>(self, input: A) -> A {
+        A::from(input.standard() / self.reduction)
+    }
+
+    /// The input angle for an output angle
+    #[must_use]
+    pub fn input<A: Angle>(self, output: A) -> A {
+        A::from(output.standard() * self.reduction)
+    }
+
+    /// The output velocity for an input velocity
+    #[must_use]
+    pub fn output_velocity<V: AngleVel>(self, input: V) -> V {
+        V::from(input.standard() / self.reduction)
+    }
+
+    /// The input velocity for an output velocity
+    #[must_use]
+    pub fn input_velocity<V: AngleVel>(self, output: V) -> V {
+        V::from(output.standard() * self.reduction)
+    }
+
+    /// The output torque for an input torque, ignoring losses in the gearing
+    #[must_use]
+    pub fn output_torque<T: Torque>(self, input: T) -> T {
+        T::from(input.standard() * self.reduction)
+    }
+
+    /// The input torque needed for an output torque, ignoring losses in the gearing
+    #[must_use]
+    pub fn input_torque<T: Torque>(self, output: T) -> T {
+        T::from(output.standard() / self.reduction)
+    }
+}
+
+/// An encoder that counts a fixed number of ticks per rotation of its shaft.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Encoder {
+    ticks_per_rotation: f64,
+}
+
+impl Encoder {
+    /// Creates an encoder that counts `ticks_per_rotation` ticks per rotation
+    #[must_use]
+    pub const fn new(ticks_per_rotation: f64) -> Self {
+        Self { ticks_per_rotation }
+    }
+
+    /// The number of ticks counted per rotation
+    #[must_use]
+    pub const fn ticks_per_rotation(self) -> f64 {
+        self.ticks_per_rotation
+    }
+
+    /// The shaft angle for a tick count
+    #[must_use]
+    pub fn angle(self, ticks: f64) -> Rotation {
+        Rotation::new(ticks / self.ticks_per_rotation)
+    }
+
+    /// The tick count for a shaft angle
+    #[must_use]
+    pub fn ticks(self, angle: impl Angle) -> f64 {
+        Rotation::from(angle.standard()).value() * self.ticks_per_rotation
+    }
+
+    /// The shaft velocity for `ticks` counted over `period`,
+    /// many motor controllers report velocity as ticks per 100 ms
+    #[must_use]
+    pub fn velocity(self, ticks: f64, period: impl Time) -> RotationPerSec {
+        RotationPerSec::new(self.angle(ticks).value() / period.standard().value())
+    }
+
+    /// The ticks counted over `period` at a shaft velocity
+    #[must_use]
+    pub fn ticks_per(self, velocity: impl AngleVel, period: impl Time) -> f64 {
+        RotationPerSec::from(velocity.standard()).value()
+            * period.standard().value()
+            * self.ticks_per_rotation
+    }
+}
@@ -0,0 +1,18 @@
+use crate::units::angle::{Radian, Rotation};
+use crate::units::angular_velocity::{RadianPerSec, RotationPerMin, RotationPerSec};
+use crate::units::quantity::dims;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(Hertz | Hz: float = "Hz");
+unit!(Kilohertz | KHz: float = "kHz");
+unit!(RevolutionsPerMinute | Rpm: float = "rpm");
+
+unit_family!(Frequency(Hertz) in dims::Frequency:
+    Kilohertz = 1000.0,
+    RevolutionsPerMinute = 1.0 / 60.0;
+    scale: Hertz, Kilohertz
+);
+
+unit_dim_analysis!(Hertz * Rotation = RotationPerSec);
+unit_dim_analysis!(Hertz * Radian = RadianPerSec);
+unit_dim_analysis!(RevolutionsPerMinute * Rotation = RotationPerMin);
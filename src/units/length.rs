@@ -1,18 +1,14 @@
 use crate::units::linear_velocity::{FeetPerSecond, MetersPerSecond};
+use crate::units::quantity::dims;
 use crate::units::time::Second;
-use crate::{unit, unit_conversion, unit_dim_analysis};
+use crate::{unit, unit_dim_analysis};
 
-unit!(Meter: float);
-unit!(Foot: float);
-unit!(Inch: float);
-unit!(Centimeter: float);
+unit!(Meter: float = "m", "meters");
+unit!(Foot: float = "ft", "feet", "'");
+unit!(Inch: float = "in", "inches", "\"");
+unit!(Centimeter: float = "cm", "centimeters");
 
-unit_conversion!(Meter(float) <-> Foot(float) ~ meter_to_foot);
-unit_conversion!(Meter(float) <-> Inch(float) ~ meter_to_inch);
-unit_conversion!(Foot(float) <-> Inch(float) ~ foot_to_inch);
-unit_conversion!(Meter(float) <-> Centimeter(float) ~ meter_to_centimeter);
-unit_conversion!(Centimeter(float) <-> Foot(float) ~ centimeter_to_foot);
-unit_conversion!(Centimeter(float) <-> Inch(float) ~ centimeter_to_inch);
+crate::unit_family!(@graph Meter in dims::Length: Foot = 0.3048, Inch = 0.0254, Centimeter = 0.01);
 
 unit_dim_analysis!(MetersPerSecond * Second = Meter);
 unit_dim_analysis!(FeetPerSecond * Second = Foot);
@@ -48,29 +44,9 @@ pub trait Distance: Into<Meter> + From<Meter> + Copy {
     fn to_meters(self) -> Meter {
         self.standard()
     }
-}
-impl<T> Distance for T where T: Into<Meter> + From<Meter> + Copy {}
-
-fn meter_to_foot(meter: f64) -> f64 {
-    meter * 3.28084
-}
 
-fn meter_to_inch(meter: f64) -> f64 {
-    meter * 3.28084 * 12.0
-}
-
-fn foot_to_inch(foot: f64) -> f64 {
-    foot * 12.0
-}
-
-fn meter_to_centimeter(meter: f64) -> f64 {
-    meter * 100.0
-}
-
-fn centimeter_to_foot(centimeter: f64) -> f64 {
-    meter_to_foot(centimeter / 100.0)
-}
-
-fn centimeter_to_inch(centimeter: f64) -> f64 {
-    meter_to_inch(centimeter / 100.0)
+    crate::unit_family_value!(Distance(Meter): Foot, Inch, Centimeter);
+    crate::unit_family_text!(Distance(Meter): Foot, Inch, Centimeter; scale: Centimeter, Meter);
 }
+impl<T> Distance for T where T: Into<Meter> + From<Meter> + Copy {}
+crate::unit_family_parse!(Distance(Meter): Foot, Inch, Centimeter);
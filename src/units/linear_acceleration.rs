@@ -1,39 +1,18 @@
-use crate::{unit, unit_conversion, unit_family};
-
-unit!(MetersPerSecSqr: float);
-unit!(KilometersPerHrSqr: float);
-unit!(MilesPerHrSqr: float);
-unit!(FeetPerSecSqr: float);
-
-unit_conversion!(MetersPerSecSqr(float) <-> KilometersPerHrSqr(float) ~ meter_to_kilometer);
-unit_conversion!(MetersPerSecSqr(float) <-> MilesPerHrSqr(float) ~ meter_to_mile);
-unit_conversion!(MetersPerSecSqr(float) <-> FeetPerSecSqr(float) ~ meter_to_feet);
-unit_conversion!(FeetPerSecSqr(float) <-> MilesPerHrSqr(float) ~ feet_to_mile);
-unit_conversion!(FeetPerSecSqr(float) <-> KilometersPerHrSqr(float) ~ feet_to_kilometer);
-unit_conversion!(MilesPerHrSqr(float) <-> KilometersPerHrSqr(float) ~ mile_to_kilometer);
-
-unit_family!(LinearVelocity(MetersPerSecSqr): KilometersPerHrSqr, MilesPerHrSqr, FeetPerSecSqr);
-
-fn meter_to_kilometer(meter: f64) -> f64 {
-    meter * 3.6
-}
-
-fn meter_to_mile(meter: f64) -> f64 {
-    meter * 2.23694
-}
-
-fn meter_to_feet(meter: f64) -> f64 {
-    meter * 3.28084
-}
-
-fn feet_to_mile(feet: f64) -> f64 {
-    meter_to_mile(feet / 3.28084)
-}
-
-fn feet_to_kilometer(feet: f64) -> f64 {
-    meter_to_kilometer(feet / 3.28084)
-}
-
-fn mile_to_kilometer(mile: f64) -> f64 {
-    meter_to_kilometer(mile / 2.23694)
-}
+use crate::units::linear_jerk::{FeetPerSecCubed, MetersPerSecCubed};
+use crate::units::quantity::dims;
+use crate::units::time::Second;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(MetersPerSecSqr: float = "m/s²", "m/s^2");
+unit!(KilometersPerHrSqr: float = "km/h²", "km/h^2");
+unit!(MilesPerHrSqr: float = "mi/h²", "mi/h^2");
+unit!(FeetPerSecSqr: float = "ft/s²", "ft/s^2");
+
+unit_family!(LinearAcceleration(MetersPerSecSqr) in dims::Acceleration:
+    KilometersPerHrSqr = 1000.0 / (3600.0 * 3600.0),
+    MilesPerHrSqr = 1609.344 / (3600.0 * 3600.0),
+    FeetPerSecSqr = 0.3048
+);
+
+unit_dim_analysis!(MetersPerSecCubed * Second = MetersPerSecSqr);
+unit_dim_analysis!(FeetPerSecCubed * Second = FeetPerSecSqr);
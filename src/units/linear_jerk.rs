@@ -0,0 +1,7 @@
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+unit!(MetersPerSecCubed: float = "m/s³", "m/s^3");
+unit!(FeetPerSecCubed: float = "ft/s³", "ft/s^3");
+
+unit_family!(LinearJerk(MetersPerSecCubed) in dims::Jerk: FeetPerSecCubed = 0.3048);
@@ -81,6 +81,55 @@ macro_rules! unit_general {
             }
         }
     };
+    ($unit_name:ident : f32) => {
+        impl From<f32> for $unit_name {
+            fn from(value: f32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<i16> for $unit_name {
+            fn from(value: i16) -> Self {
+                Self(f32::from(value))
+            }
+        }
+
+        impl From<i8> for $unit_name {
+            fn from(value: i8) -> Self {
+                Self(f32::from(value))
+            }
+        }
+
+        impl From<u16> for $unit_name {
+            fn from(value: u16) -> Self {
+                Self(f32::from(value))
+            }
+        }
+
+        impl From<u8> for $unit_name {
+            fn from(value: u8) -> Self {
+                Self(f32::from(value))
+            }
+        }
+
+        impl From<&$unit_name> for $unit_name {
+            fn from(value: &$unit_name) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl From<$unit_name> for f32 {
+            fn from(value: $unit_name) -> Self {
+                value.0
+            }
+        }
+
+        impl std::fmt::Display for $unit_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({}f32)", stringify!($unit_name), self.0)
+            }
+        }
+    };
     ($unit_name:ident : i64) => {
         impl From<i64> for $unit_name {
             fn from(value: i64) -> Self {
@@ -440,6 +489,95 @@ macro_rules! unit_neg {
     };
 }
 
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_point {
+    ($unit_name:ident : $delta:ident) => {
+        impl<D> std::ops::Add<D> for $unit_name
+        where
+            D: Into<$delta>,
+        {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: D) -> Self::Output {
+                Self(self.0 + rhs.into().0)
+            }
+        }
+
+        impl<D> std::ops::Sub<D> for $unit_name
+        where
+            D: Into<$delta>,
+        {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: D) -> Self::Output {
+                Self(self.0 - rhs.into().0)
+            }
+        }
+
+        impl<D> std::ops::AddAssign<D> for $unit_name
+        where
+            D: Into<$delta>,
+        {
+            #[inline]
+            fn add_assign(&mut self, rhs: D) {
+                self.0 += rhs.into().0;
+            }
+        }
+
+        impl<D> std::ops::SubAssign<D> for $unit_name
+        where
+            D: Into<$delta>,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: D) {
+                self.0 -= rhs.into().0;
+            }
+        }
+
+        impl std::ops::Sub for $unit_name {
+            type Output = $delta;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                $delta(self.0 - rhs.0)
+            }
+        }
+
+        impl std::ops::Add<$unit_name> for $delta {
+            type Output = $unit_name;
+            #[inline]
+            fn add(self, rhs: $unit_name) -> Self::Output {
+                $unit_name(self.0 + rhs.0)
+            }
+        }
+
+        impl num::ToPrimitive for $unit_name {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_i64()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_u64()
+            }
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0)
+            }
+        }
+
+        impl num::FromPrimitive for $unit_name {
+            fn from_i64(n: i64) -> Option<Self> {
+                f64::from_i64(n).map(Self)
+            }
+            fn from_u64(n: u64) -> Option<Self> {
+                f64::from_u64(n).map(Self)
+            }
+            fn from_f64(n: f64) -> Option<Self> {
+                Some(Self(n))
+            }
+        }
+    };
+}
+
 /// NOT FOR DIRECT USE
 #[doc(hidden)]
 #[macro_export]
@@ -492,6 +630,9 @@ macro_rules! unit_num {
             fn to_u64(&self) -> Option<u64> {
                 self.0.to_u64()
             }
+            fn to_f64(&self) -> Option<f64> {
+                self.0.to_f64()
+            }
         }
         impl num::FromPrimitive for $unit_name {
             fn from_i64(n: i64) -> Option<Self> {
@@ -528,6 +669,23 @@ macro_rules! unit_structure {
             }
         }
     };
+    ($unit_name:ident : f32) => {
+        impl $crate::structure::FrcStructure for $unit_name {
+            const TYPE: &'static str = "float32";
+            const SIZE: usize = 4;
+            const SCHEMA_SUPPLIER: fn() -> String = || String::with_capacity(0);
+
+            fn pack(&self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&f32::to_le_bytes(self.0));
+            }
+
+            fn unpack(buffer: &mut std::io::Cursor<&[u8]>) -> Self {
+                let mut value_buffer = [0u8; Self::SIZE];
+                let _ = std::io::Read::read_exact(buffer, &mut value_buffer);
+                Self(f32::from_le_bytes(value_buffer))
+            }
+        }
+    };
     ($unit_name:ident : i64) => {
         impl $crate::structure::FrcStructure for $unit_name {
             const TYPE: &'static str = "int64";
@@ -563,3 +721,442 @@ macro_rules! unit_structure {
         }
     };
 }
+
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_name {
+    ($unit_name:ident $(| $unit_alias:ident)* $(= $symbol:literal $(, $text_alias:literal)*)?) => {
+        impl $crate::units::Unit for $unit_name {
+            const NAME: &'static str = stringify!($unit_name);
+            $(const SYMBOL: &'static str = $symbol;)?
+            const ALIASES: &'static [&'static str] =
+                &[$(stringify!($unit_alias),)* $($($text_alias,)*)?];
+        }
+    };
+}
+
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_str {
+    ($unit_name:ident : $type:ty) => {
+        impl std::str::FromStr for $unit_name {
+            type Err = $crate::units::UnitParseError;
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let (value, suffix) = $crate::units::split_number::<$type>(text)?;
+                if suffix.is_empty() || $crate::units::matches_suffix::<Self>(suffix) {
+                    Ok(Self(value))
+                } else {
+                    Err($crate::units::UnitParseError::UnknownSuffix {
+                        suffix: suffix.to_owned(),
+                        expected: <Self as $crate::units::Unit>::NAME,
+                    })
+                }
+            }
+        }
+
+        impl $unit_name {
+            /// Formats this value with the symbol of the unit, like `3.5 ft`.
+            #[must_use]
+            #[inline]
+            pub const fn display(self) -> $crate::units::UnitDisplay<$type> {
+                $crate::units::UnitDisplay::new(self.0, <Self as $crate::units::Unit>::SYMBOL)
+            }
+        }
+    };
+}
+
+/// NOT FOR DIRECT USE
+#[cfg(feature = "value-union")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_value {
+    ($unit_name:ident : f64) => {
+        $crate::unit_value!($unit_name : f64, Double);
+    };
+    ($unit_name:ident : f32) => {
+        $crate::unit_value!($unit_name : f32, Float);
+    };
+    ($unit_name:ident : i64) => {
+        $crate::unit_value!($unit_name : i64, Int);
+    };
+    ($unit_name:ident : u64) => {
+        // `FrcValue` has no unsigned integer, values past `i64::MAX` saturate
+        // so the unit can be statically typed like every other unit
+        #[doc = "Converts to [`FrcValue::Int`](crate::value::FrcValue::Int),"]
+        #[doc = "values past [`i64::MAX`] are clamped to [`i64::MAX`]."]
+        impl From<$unit_name> for $crate::value::FrcValue {
+            fn from(value: $unit_name) -> Self {
+                Self::Int(i64::try_from(value.0).unwrap_or(i64::MAX))
+            }
+        }
+        impl $crate::value::StaticallyFrcTyped for $unit_name {
+            const TYPE: $crate::value::FrcType = $crate::value::FrcType::Int;
+        }
+        $crate::unit_value!(@try_from $unit_name : u64);
+    };
+    ($unit_name:ident : $type:ty, $variant:ident) => {
+        impl From<$unit_name> for $crate::value::FrcValue {
+            fn from(value: $unit_name) -> Self {
+                Self::$variant(value.0)
+            }
+        }
+        impl $crate::value::StaticallyFrcTyped for $unit_name {
+            const TYPE: $crate::value::FrcType = $crate::value::FrcType::$variant;
+        }
+        $crate::unit_value!(@try_from $unit_name : $type);
+    };
+    (@try_from $unit_name:ident : $type:ty) => {
+        impl TryFrom<$crate::value::FrcValue> for $unit_name {
+            type Error = $crate::value::FrcValueCastError;
+            fn try_from(value: $crate::value::FrcValue) -> Result<Self, Self::Error> {
+                <$type>::try_from(value).map(Self).map_err(|err| match err {
+                    $crate::value::FrcValueCastError::InvalidCastTo(frc_type, _, reason) => {
+                        $crate::value::FrcValueCastError::InvalidCastTo(
+                            frc_type,
+                            stringify!($unit_name),
+                            reason,
+                        )
+                    }
+                    err => err,
+                })
+            }
+        }
+    };
+}
+
+/// NOT FOR DIRECT USE
+#[cfg(not(feature = "value-union"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_value {
+    ($($tt:tt)*) => {};
+}
+
+/// NOT FOR DIRECT USE
+#[cfg(feature = "value-union")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_family_value {
+    ($family_name:ident ( $standard:ident ): $($unit_name:ident),*) => {
+        #[doc = "Converts a value tagged with the name of any unit in this family."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = "Returns an error if the unit is not part of this family"]
+        #[doc = "or the value is not numeric."]
+        fn from_frc_value(
+            value: $crate::value::FrcValue,
+            unit: &str,
+        ) -> Result<Self, $crate::value::FrcValueCastError> {
+            match unit {
+                stringify!($standard) => Ok(Self::from($standard::try_from(value)?)),
+                $(
+                    stringify!($unit_name) => {
+                        Ok(Self::from($standard::from($unit_name::try_from(value)?)))
+                    }
+                )*
+                _ => Err($crate::value::FrcValueCastError::InvalidCastTo(
+                    value.get_type(),
+                    stringify!($family_name),
+                    $crate::value::CastErrorReason::UnknownUnit,
+                )),
+            }
+        }
+
+        #[doc = "Converts an [`FrcUnitValue`](crate::units::FrcUnitValue) of any unit in this family."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = "Returns an error if the unit is not part of this family"]
+        #[doc = "or the value is not numeric."]
+        fn from_unit_value(
+            value: $crate::units::FrcUnitValue,
+        ) -> Result<Self, $crate::value::FrcValueCastError> {
+            Self::from_frc_value(value.value, &value.unit)
+        }
+    };
+}
+
+/// NOT FOR DIRECT USE
+#[cfg(not(feature = "value-union"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_family_value {
+    ($($tt:tt)*) => {};
+}
+
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_family_parse {
+    ($family_name:ident ( $standard:ident ): $($unit_name:ident),*) => {
+        $(
+            impl $crate::units::ParseFamily for $unit_name {
+                #[inline]
+                fn parse_family(text: &str) -> Result<Self, $crate::units::UnitParseError> {
+                    <Self as $family_name>::parse(text)
+                }
+
+                #[inline]
+                fn from_family(
+                    value: f64,
+                    suffix: &str,
+                ) -> Result<Self, $crate::units::UnitParseError> {
+                    <Self as $family_name>::from_suffix(value, suffix)
+                }
+            }
+        )*
+        impl $crate::units::ParseFamily for $standard {
+            #[inline]
+            fn parse_family(text: &str) -> Result<Self, $crate::units::UnitParseError> {
+                <Self as $family_name>::parse(text)
+            }
+
+            #[inline]
+            fn from_family(value: f64, suffix: &str) -> Result<Self, $crate::units::UnitParseError> {
+                <Self as $family_name>::from_suffix(value, suffix)
+            }
+        }
+    };
+}
+
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_family_text {
+    ($family_name:ident ( $standard:ident ): $($unit_name:ident),*) => {
+        $crate::unit_family_text!($family_name($standard): $($unit_name),*; scale: $standard);
+    };
+    (
+        $family_name:ident ( $standard:ident ): $($unit_name:ident),*;
+        scale: $first:ident $(, $scale:ident)* $(| $ladder_first:ident $(, $ladder:ident)*)*
+    ) => {
+        #[doc = "Parses text like `12in` or `3.5 ft` in any unit of this family,"]
+        #[doc = "a number without a suffix is read in the standard unit."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = "Returns an error if the text does not start with a number"]
+        #[doc = "or the suffix is not a symbol, name or alias of a unit in this family."]
+        fn parse(text: &str) -> Result<Self, $crate::units::UnitParseError> {
+            let (value, suffix) = $crate::units::split_number::<f64>(text)?;
+            Self::from_suffix(value, suffix)
+        }
+
+        #[doc = "Converts `value` measured in the unit named by `suffix` to this unit,"]
+        #[doc = "an empty suffix is read in the standard unit."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = "Returns an error if the suffix is not a symbol, name or alias of a unit in this family"]
+        #[doc = "or the value is out of range for that unit."]
+        fn from_suffix(value: f64, suffix: &str) -> Result<Self, $crate::units::UnitParseError> {
+            let out_of_range = || $crate::units::UnitParseError::InvalidNumber(value.to_string());
+            if suffix.is_empty() || $crate::units::matches_suffix::<$standard>(suffix) {
+                return <$standard as num::FromPrimitive>::from_f64(value)
+                    .map(Self::from)
+                    .ok_or_else(out_of_range);
+            }
+            $(
+                if $crate::units::matches_suffix::<$unit_name>(suffix) {
+                    return <$unit_name as num::FromPrimitive>::from_f64(value)
+                        .map(|value| Self::from($standard::from(value)))
+                        .ok_or_else(out_of_range);
+                }
+            )*
+            Err($crate::units::UnitParseError::UnknownSuffix {
+                suffix: suffix.to_owned(),
+                expected: stringify!($family_name),
+            })
+        }
+
+        #[doc = "Formats this value in the largest unit of the family's scale"]
+        #[doc = "that keeps the number at least one, like `1.5 MB` instead of `1500000 B`."]
+        #[doc = ""]
+        #[doc = "Families with more than one scale use the first one here,"]
+        #[doc = "the units of the other scales have their own `display_scaled` for their scale."]
+        #[must_use]
+        fn display_scaled(self) -> $crate::units::UnitDisplay<f64> {
+            let standard = self.standard();
+            $crate::unit_family_text!(@ladder standard: $first $(, $scale)*)
+        }
+    };
+    (@scales $standard:ident; $first:ident $(, $scale:ident)* $(| $ladder_first:ident $(, $ladder:ident)*)*) => {
+        $(
+            $crate::unit_family_text!(
+                @scaled $standard [$ladder_first $(, $ladder)*] $ladder_first $(, $ladder)*
+            );
+        )*
+    };
+    (@scaled $standard:ident [$($ladder:ident),+]) => {};
+    (@scaled $standard:ident [$($ladder:ident),+] $unit_name:ident $(, $rest:ident)*) => {
+        impl $unit_name {
+            /// Formats this value in the largest unit of this unit's scale
+            /// that keeps the number at least one, like `1.5 MiB` instead of `1536 KiB`.
+            #[must_use]
+            pub fn display_scaled(self) -> $crate::units::UnitDisplay<f64> {
+                let standard = $standard::from(self);
+                $crate::unit_family_text!(@ladder standard: $($ladder),+)
+            }
+        }
+        $crate::unit_family_text!(@scaled $standard [$($ladder),+] $($rest),*);
+    };
+    (@ladder $standard:ident : $first:ident $(, $scale:ident)*) => {{
+        let display = $crate::units::UnitDisplay::new(
+            num::ToPrimitive::to_f64(&$first::from($standard)).unwrap_or(f64::NAN),
+            <$first as $crate::units::Unit>::SYMBOL,
+        );
+        $(
+            let value = num::ToPrimitive::to_f64(&$scale::from($standard)).unwrap_or(f64::NAN);
+            let display = if value.abs() >= 1.0 {
+                $crate::units::UnitDisplay::new(value, <$scale as $crate::units::Unit>::SYMBOL)
+            } else {
+                display
+            };
+        )*
+        display
+    }};
+}
+
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_real {
+    ($unit_name:ident) => {
+        impl<T: simba::scalar::RealField> $unit_name<T> {
+            /// Creates a new instance of the unit with the given value.
+            #[must_use]
+            #[inline]
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            /// Returns the inner scalar value.
+            #[must_use]
+            #[inline]
+            pub fn value(self) -> T {
+                self.0
+            }
+
+            /// Converts the backing scalar of this unit to another [`RealField`](simba::scalar::RealField).
+            ///
+            /// The value passes through [`f64`], so scalars carrying more than a real value
+            /// (like dual numbers) only keep their real part.
+            #[must_use]
+            #[inline]
+            pub fn cast<U: simba::scalar::RealField>(self) -> $unit_name<U> {
+                $unit_name(U::from_subset(&self.into_f64()))
+            }
+
+            #[inline]
+            fn into_f64(self) -> f64 {
+                <T as simba::scalar::SupersetOf<f64>>::to_subset(&self.0).unwrap_or(f64::NAN)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> From<f64> for $unit_name<T> {
+            fn from(value: f64) -> Self {
+                Self(T::from_subset(&value))
+            }
+        }
+
+        impl<T: simba::scalar::RealField> From<$unit_name<T>> for f64 {
+            fn from(value: $unit_name<T>) -> Self {
+                value.into_f64()
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::Add for $unit_name<T> {
+            type Output = Self;
+            #[must_use]
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::Sub for $unit_name<T> {
+            type Output = Self;
+            #[must_use]
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::Mul<T> for $unit_name<T> {
+            type Output = Self;
+            #[must_use]
+            #[inline]
+            fn mul(self, rhs: T) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::Div<T> for $unit_name<T> {
+            type Output = Self;
+            #[must_use]
+            #[inline]
+            fn div(self, rhs: T) -> Self::Output {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::Neg for $unit_name<T> {
+            type Output = Self;
+            #[must_use]
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::AddAssign for $unit_name<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::SubAssign for $unit_name<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::MulAssign<T> for $unit_name<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::ops::DivAssign<T> for $unit_name<T> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl<T: simba::scalar::RealField> std::fmt::Display for $unit_name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({})", stringify!($unit_name), self.0)
+            }
+        }
+
+        impl<T: serde::Serialize> serde::Serialize for $unit_name<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for $unit_name<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                T::deserialize(deserializer).map(|value| Self(value))
+            }
+        }
+
+        impl<T: simba::scalar::RealField + Copy> $crate::units::Unit for $unit_name<T> {
+            const NAME: &'static str = stringify!($unit_name);
+        }
+    };
+}
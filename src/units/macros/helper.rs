@@ -18,55 +18,65 @@ macro_rules! complex_type_name {
 #[macro_export]
 macro_rules! inner_unit_conversion {
     ($unit_a:ident $type_a:ty | $unit_b:ident $type_b:ty : $conv_fn:ident) => {
-        #[allow(trivial_numeric_casts)]
+        $crate::inner_unit_conversion!(
+            $unit_a $type_a | $unit_b $type_b : $conv_fn,
+            |value: $type_b| {
+                // integer units only round once they pass 2^53 of their unit,
+                // 285 years for the microsecond clock
+                #[allow(clippy::cast_precision_loss)]
+                let (value, scale) = (value as f64, $conv_fn(<$type_a>::from(1.0)) as f64);
+                (value / scale) as $type_a
+            }
+        );
+    };
+    ($unit_a:ident $type_a:ty | $unit_b:ident $type_b:ty : $conv_fn:expr, $rev_fn:expr) => {
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl From<$unit_a> for $unit_b {
             fn from(value: $unit_a) -> Self {
                 $unit_b($conv_fn(value.0))
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl From<&$unit_a> for $unit_b {
             fn from(value: &$unit_a) -> Self {
                 $unit_b($conv_fn(value.0))
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl From<$unit_b> for $unit_a {
             fn from(value: $unit_b) -> Self {
-                $unit_a((value.0 / $conv_fn(<$type_a>::from(1.0)) as $type_b) as $type_a)
+                $unit_a($rev_fn(value.0))
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl From<&$unit_b> for $unit_a {
             fn from(value: &$unit_b) -> Self {
-                $unit_a((value.0 / $conv_fn(<$type_a>::from(1.0)) as $type_b) as $type_a)
+                $unit_a($rev_fn(value.0))
             }
         }
 
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl std::cmp::PartialEq<$unit_a> for $unit_b {
             fn eq(&self, other: &$unit_a) -> bool {
                 self.0 == ($conv_fn(other.0) as $type_b)
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl std::cmp::PartialOrd<$unit_a> for $unit_b {
             fn partial_cmp(&self, other: &$unit_a) -> Option<std::cmp::Ordering> {
                 self.0.partial_cmp(&$conv_fn(other.0))
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl std::cmp::PartialEq<$unit_b> for $unit_a {
             fn eq(&self, other: &$unit_b) -> bool {
-                self.0 == ((other.0 / $conv_fn(<$type_a>::from(1.0)) as $type_b) as $type_a)
+                self.0 == $rev_fn(other.0)
             }
         }
-        #[allow(trivial_numeric_casts)]
+        #[allow(trivial_numeric_casts, clippy::redundant_closure_call)]
         impl std::cmp::PartialOrd<$unit_b> for $unit_a {
             fn partial_cmp(&self, other: &$unit_b) -> Option<std::cmp::Ordering> {
-                self.0.partial_cmp(
-                    &((other.0 / $conv_fn(<$type_a>::from(1.0)) as $type_b) as $type_a),
-                )
+                self.0.partial_cmp(&$rev_fn(other.0))
             }
         }
     };
@@ -0,0 +1,283 @@
+/// NOT FOR DIRECT USE
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unit_nalgebra {
+    ($unit_name:ident : $float:ident) => {
+        impl simba::simd::SimdValue for $unit_name {
+            const LANES: usize = 1;
+            type Element = $unit_name;
+            type SimdBool = bool;
+
+            #[inline]
+            fn splat(val: Self::Element) -> Self {
+                val
+            }
+            #[inline]
+            fn extract(&self, _: usize) -> Self::Element {
+                *self
+            }
+            #[inline]
+            unsafe fn extract_unchecked(&self, _: usize) -> Self::Element {
+                *self
+            }
+            #[inline]
+            fn replace(&mut self, _: usize, val: Self::Element) {
+                *self = val;
+            }
+            #[inline]
+            unsafe fn replace_unchecked(&mut self, _: usize, val: Self::Element) {
+                *self = val;
+            }
+            #[inline]
+            fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+                if cond {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+
+        impl simba::scalar::Field for $unit_name {}
+
+        impl simba::scalar::SubsetOf<$unit_name> for $unit_name {
+            #[inline]
+            fn to_superset(&self) -> $unit_name {
+                *self
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &$unit_name) -> Self {
+                *element
+            }
+            #[inline]
+            fn is_in_subset(_: &$unit_name) -> bool {
+                true
+            }
+        }
+
+        $crate::unit_nalgebra!(@subset $unit_name : $float, f32);
+        $crate::unit_nalgebra!(@subset $unit_name : $float, f64);
+
+        impl num::Signed for $unit_name {
+            #[inline]
+            fn abs(&self) -> Self {
+                Self(num::Signed::abs(&self.0))
+            }
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                Self(num::Signed::abs_sub(&self.0, &other.0))
+            }
+            #[inline]
+            fn signum(&self) -> Self {
+                Self(num::Signed::signum(&self.0))
+            }
+            #[inline]
+            fn is_positive(&self) -> bool {
+                num::Signed::is_positive(&self.0)
+            }
+            #[inline]
+            fn is_negative(&self) -> bool {
+                num::Signed::is_negative(&self.0)
+            }
+        }
+
+        impl approx::AbsDiffEq for $unit_name {
+            type Epsilon = Self;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                Self(<$float as approx::AbsDiffEq>::default_epsilon())
+            }
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                approx::AbsDiffEq::abs_diff_eq(&self.0, &other.0, epsilon.0)
+            }
+        }
+
+        impl approx::RelativeEq for $unit_name {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                Self(<$float as approx::RelativeEq>::default_max_relative())
+            }
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                approx::RelativeEq::relative_eq(&self.0, &other.0, epsilon.0, max_relative.0)
+            }
+        }
+
+        impl approx::UlpsEq for $unit_name {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$float as approx::UlpsEq>::default_max_ulps()
+            }
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                approx::UlpsEq::ulps_eq(&self.0, &other.0, epsilon.0, max_ulps)
+            }
+        }
+
+        impl simba::scalar::ComplexField for $unit_name {
+            type RealField = Self;
+
+            #[inline]
+            fn is_finite(&self) -> bool {
+                self.0.is_finite()
+            }
+            #[inline]
+            fn try_sqrt(self) -> Option<Self> {
+                simba::scalar::ComplexField::try_sqrt(self.0).map(Self)
+            }
+            #[inline]
+            fn from_real(re: Self::RealField) -> Self {
+                re
+            }
+            #[inline]
+            fn real(self) -> Self::RealField {
+                self
+            }
+            #[inline]
+            fn imaginary(self) -> Self::RealField {
+                Self(0.0)
+            }
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Self(simba::scalar::ComplexField::mul_add(self.0, a.0, b.0))
+            }
+            #[inline]
+            fn hypot(self, other: Self) -> Self::RealField {
+                Self(simba::scalar::ComplexField::hypot(self.0, other.0))
+            }
+            #[inline]
+            fn scale(self, factor: Self::RealField) -> Self {
+                Self(self.0 * factor.0)
+            }
+            #[inline]
+            fn unscale(self, factor: Self::RealField) -> Self {
+                Self(self.0 / factor.0)
+            }
+            #[inline]
+            fn log(self, base: Self::RealField) -> Self {
+                Self(simba::scalar::ComplexField::log(self.0, base.0))
+            }
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                Self(simba::scalar::ComplexField::powi(self.0, n))
+            }
+            #[inline]
+            fn powf(self, n: Self::RealField) -> Self {
+                Self(simba::scalar::ComplexField::powf(self.0, n.0))
+            }
+            #[inline]
+            fn powc(self, n: Self) -> Self {
+                Self(simba::scalar::ComplexField::powc(self.0, n.0))
+            }
+            $crate::forward_into! {
+                simba::scalar::ComplexField::modulus as modulus(self) -> Self::RealField;
+                simba::scalar::ComplexField::modulus_squared as modulus_squared(self) -> Self::RealField;
+                simba::scalar::ComplexField::argument as argument(self) -> Self::RealField;
+                simba::scalar::ComplexField::norm1 as norm1(self) -> Self::RealField;
+                simba::scalar::ComplexField::abs as abs(self) -> Self::RealField;
+                simba::scalar::ComplexField::floor as floor(self) -> Self;
+                simba::scalar::ComplexField::ceil as ceil(self) -> Self;
+                simba::scalar::ComplexField::round as round(self) -> Self;
+                simba::scalar::ComplexField::trunc as trunc(self) -> Self;
+                simba::scalar::ComplexField::fract as fract(self) -> Self;
+                simba::scalar::ComplexField::recip as recip(self) -> Self;
+                simba::scalar::ComplexField::conjugate as conjugate(self) -> Self;
+                simba::scalar::ComplexField::sin as sin(self) -> Self;
+                simba::scalar::ComplexField::cos as cos(self) -> Self;
+                simba::scalar::ComplexField::tan as tan(self) -> Self;
+                simba::scalar::ComplexField::asin as asin(self) -> Self;
+                simba::scalar::ComplexField::acos as acos(self) -> Self;
+                simba::scalar::ComplexField::atan as atan(self) -> Self;
+                simba::scalar::ComplexField::sinh as sinh(self) -> Self;
+                simba::scalar::ComplexField::cosh as cosh(self) -> Self;
+                simba::scalar::ComplexField::tanh as tanh(self) -> Self;
+                simba::scalar::ComplexField::asinh as asinh(self) -> Self;
+                simba::scalar::ComplexField::acosh as acosh(self) -> Self;
+                simba::scalar::ComplexField::atanh as atanh(self) -> Self;
+                simba::scalar::ComplexField::log2 as log2(self) -> Self;
+                simba::scalar::ComplexField::log10 as log10(self) -> Self;
+                simba::scalar::ComplexField::ln as ln(self) -> Self;
+                simba::scalar::ComplexField::ln_1p as ln_1p(self) -> Self;
+                simba::scalar::ComplexField::sqrt as sqrt(self) -> Self;
+                simba::scalar::ComplexField::exp as exp(self) -> Self;
+                simba::scalar::ComplexField::exp2 as exp2(self) -> Self;
+                simba::scalar::ComplexField::exp_m1 as exp_m1(self) -> Self;
+                simba::scalar::ComplexField::cbrt as cbrt(self) -> Self;
+            }
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = simba::scalar::ComplexField::sin_cos(self.0);
+                (Self(sin), Self(cos))
+            }
+        }
+
+        impl simba::scalar::RealField for $unit_name {
+            #[inline]
+            fn is_sign_positive(&self) -> bool {
+                self.0.is_sign_positive()
+            }
+            #[inline]
+            fn is_sign_negative(&self) -> bool {
+                self.0.is_sign_negative()
+            }
+            #[inline]
+            fn min_value() -> Option<Self> {
+                <$float as simba::scalar::RealField>::min_value().map(Self)
+            }
+            #[inline]
+            fn max_value() -> Option<Self> {
+                <$float as simba::scalar::RealField>::max_value().map(Self)
+            }
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                Self(simba::scalar::RealField::clamp(self.0, min.0, max.0))
+            }
+            $crate::forward_into_args! {
+                simba::scalar::RealField::copysign as copysign(self, sign: Self) -> Self;
+                simba::scalar::RealField::max as max(self, other: Self) -> Self;
+                simba::scalar::RealField::min as min(self, other: Self) -> Self;
+                simba::scalar::RealField::atan2 as atan2(self, other: Self) -> Self;
+            }
+            $crate::constant! {
+                pi() -> Self(<$float as simba::scalar::RealField>::pi());
+                two_pi() -> Self(<$float as simba::scalar::RealField>::two_pi());
+                frac_pi_2() -> Self(<$float as simba::scalar::RealField>::frac_pi_2());
+                frac_pi_3() -> Self(<$float as simba::scalar::RealField>::frac_pi_3());
+                frac_pi_4() -> Self(<$float as simba::scalar::RealField>::frac_pi_4());
+                frac_pi_6() -> Self(<$float as simba::scalar::RealField>::frac_pi_6());
+                frac_pi_8() -> Self(<$float as simba::scalar::RealField>::frac_pi_8());
+                frac_1_pi() -> Self(<$float as simba::scalar::RealField>::frac_1_pi());
+                frac_2_pi() -> Self(<$float as simba::scalar::RealField>::frac_2_pi());
+                frac_2_sqrt_pi() -> Self(<$float as simba::scalar::RealField>::frac_2_sqrt_pi());
+                e() -> Self(<$float as simba::scalar::RealField>::e());
+                log2_e() -> Self(<$float as simba::scalar::RealField>::log2_e());
+                log10_e() -> Self(<$float as simba::scalar::RealField>::log10_e());
+                ln_2() -> Self(<$float as simba::scalar::RealField>::ln_2());
+                ln_10() -> Self(<$float as simba::scalar::RealField>::ln_10());
+            }
+        }
+    };
+    (@subset $unit_name:ident : $float:ident, $primitive:ident) => {
+        impl simba::scalar::SubsetOf<$unit_name> for $primitive {
+            #[inline]
+            fn to_superset(&self) -> $unit_name {
+                $unit_name(simba::scalar::SupersetOf::<$primitive>::from_subset(self))
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &$unit_name) -> Self {
+                simba::scalar::SupersetOf::<$primitive>::to_subset_unchecked(&element.0)
+            }
+            #[inline]
+            fn is_in_subset(element: &$unit_name) -> bool {
+                simba::scalar::SupersetOf::<$primitive>::is_in_subset(&element.0)
+            }
+        }
+    };
+}
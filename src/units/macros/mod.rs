@@ -4,6 +4,8 @@ mod components;
 mod number;
 #[macro_use]
 mod helper;
+#[macro_use]
+mod linalg;
 #[doc(hidden)]
 pub use paste;
 
@@ -18,9 +20,70 @@ pub use paste;
 /// unit!(RadianInt: int);
 /// unit!(RotationUint: uint);
 /// ```
+///
+/// Float units implement [`RealField`](simba::scalar::RealField) so they can be used as
+/// [`nalgebra`] scalars, a `Vector3<Meter>` keeps its unit through sums, differences and norms.
+/// Products between elements such as dot products and matrix multiplication are not
+/// dimension-checked, their result keeps the element unit.
+///
+/// Angles in any unit other than radians are declared as `angle`,
+/// a float unit that is not a [`RealField`](simba::scalar::RealField)
+/// since its trigonometry always takes radians.
+/// ```
+/// use frclib_core::unit;
+///
+/// unit!(Gradian: angle = "gon");
+///
+/// assert_eq!(Gradian::new(100.0) + Gradian::new(100.0), Gradian::new(200.0));
+/// ```
+///
+/// Units can also be backed by an [`f32`] or by any [`RealField`](simba::scalar::RealField) scalar,
+/// use [`unit_backing!`](crate::unit_backing) to convert them to and from the [`f64`] unit.
+/// ```
+/// use frclib_core::unit;
+///
+/// unit!(DegreeF32: f32);
+/// unit!(DegreeReal: real);
+///
+/// let degrees: DegreeReal<f32> = DegreeReal::new(90.0);
+/// assert_eq!(degrees.cast::<f64>(), DegreeReal(90.0));
+/// ```
+///
+/// Units on an offset scale like temperatures are declared as a `point` of a linear delta unit,
+/// a point minus a point is a delta and a point plus a delta is a point.
+/// Points have no other arithmetic so an offset is never scaled or added twice.
+/// ```
+/// use frclib_core::unit;
+///
+/// unit!(CelsiusDelta: float);
+/// unit!(Celsius: point(CelsiusDelta));
+///
+/// let warmer = Celsius::new(20.0) + CelsiusDelta::new(5.0);
+/// assert_eq!(warmer, Celsius::new(25.0));
+/// assert_eq!(warmer - Celsius::new(20.0), CelsiusDelta::new(5.0));
+/// ```
+///
+/// A symbol and extra aliases can follow the backing type,
+/// they are used to display the unit and to parse it from text.
+/// ```
+/// use frclib_core::unit;
+///
+/// unit!(Furlong | Furlongs: float = "fur", "furlong");
+///
+/// assert_eq!("2 fur".parse::<Furlong>(), Ok(Furlong(2.0)));
+/// assert_eq!("2 furlongs".parse::<Furlong>(), Ok(Furlong(2.0)));
+/// assert_eq!(format!("{:.1}", Furlong(2.0).display()), "2.0 fur");
+/// ```
 #[macro_export]
 macro_rules! unit {
-    ($unit_name:ident $( | $unit_alias:ident)* : float) => {
+    ($unit_name:ident $( | $unit_alias:ident)* : float $(= $symbol:literal $(, $text_alias:literal)*)?) => {
+        $crate::unit!(@f64 $unit_name $(| $unit_alias)* $(= $symbol $(, $text_alias)*)?);
+        $crate::unit_nalgebra!($unit_name : f64);
+    };
+    ($unit_name:ident $( | $unit_alias:ident)* : angle $(= $symbol:literal $(, $text_alias:literal)*)?) => {
+        $crate::unit!(@f64 $unit_name $(| $unit_alias)* $(= $symbol $(, $text_alias)*)?);
+    };
+    (@f64 $unit_name:ident $( | $unit_alias:ident)* $(= $symbol:literal $(, $text_alias:literal)*)?) => {
         /// A unit of measurement.
         /// This is a newtype wrapper around a [`f64`].
         #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
@@ -63,8 +126,120 @@ macro_rules! unit {
         $crate::unit_num!($unit_name : f64);
         $crate::unit_float!($unit_name);
         $crate::unit_structure!($unit_name : f64);
+        $crate::unit_value!($unit_name : f64);
+        $crate::unit_str!($unit_name : f64);
+        $crate::unit_name!($unit_name $(| $unit_alias)* $(= $symbol $(, $text_alias)*)?);
+    };
+    ($unit_name:ident $( | $unit_alias:ident)* : f32 $(= $symbol:literal $(, $text_alias:literal)*)?) => {
+        /// A unit of measurement.
+        /// This is a newtype wrapper around a [`f32`].
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        pub struct $unit_name(pub f32);
+
+        $crate::units::macros::paste::paste! {
+            $(
+                #[doc = "A unit of measurement, this is an alias for [`" $unit_name "`]."]
+                #[doc = "This is a newtype wrapper around a [`f32`]."]
+                pub type $unit_alias = $unit_name;
+            )*
+        }
+
+        impl std::hash::Hash for $unit_name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl $unit_name {
+            /// Creates a new instance of the unit with the given value.
+            #[must_use]
+            #[inline]
+            pub const fn new(value: f32) -> Self {
+                Self(value)
+            }
+
+            /// Returns the inner [`f32`] value.
+            #[must_use]
+            #[inline]
+            pub const fn value(self) -> f32 {
+                self.0
+            }
+        }
+
+        $crate::unit_general!($unit_name : f32);
+        $crate::unit_binops!($unit_name : f32);
+        $crate::unit_neg!($unit_name : f32);
+        $crate::unit_serde!($unit_name : f32);
+        $crate::unit_num!($unit_name : f32);
+        $crate::unit_float!($unit_name : f32);
+        $crate::unit_nalgebra!($unit_name : f32);
+        $crate::unit_structure!($unit_name : f32);
+        $crate::unit_value!($unit_name : f32);
+        $crate::unit_str!($unit_name : f32);
+        $crate::unit_name!($unit_name $(| $unit_alias)* $(= $symbol $(, $text_alias)*)?);
+    };
+    ($unit_name:ident $( | $unit_alias:ident)* : point($delta:ident) $(= $symbol:literal $(, $text_alias:literal)*)?) => {
+        /// A unit of measurement for an absolute point on an offset scale.
+        /// This is a newtype wrapper around a [`f64`].
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        pub struct $unit_name(pub f64);
+
+        $crate::units::macros::paste::paste! {
+            $(
+                #[doc = "A unit of measurement, this is an alias for [`" $unit_name "`]."]
+                #[doc = "This is a newtype wrapper around a [`f64`]."]
+                pub type $unit_alias = $unit_name;
+            )*
+        }
+
+        impl std::hash::Hash for $unit_name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl $unit_name {
+            /// Creates a new instance of the unit with the given value.
+            #[must_use]
+            #[inline]
+            pub const fn new(value: f64) -> Self {
+                Self(value)
+            }
+
+            /// Returns the inner [`f64`] value.
+            #[must_use]
+            #[inline]
+            pub const fn value(self) -> f64 {
+                self.0
+            }
+        }
+
+        $crate::unit_general!($unit_name : f64);
+        $crate::unit_point!($unit_name : $delta);
+        $crate::unit_serde!($unit_name : f64);
+        $crate::unit_structure!($unit_name : f64);
+        $crate::unit_value!($unit_name : f64);
+        $crate::unit_str!($unit_name : f64);
+        $crate::unit_name!($unit_name $(| $unit_alias)* $(= $symbol $(, $text_alias)*)?);
+    };
+    ($unit_name:ident $( | $unit_alias:ident)* : real) => {
+        /// A unit of measurement.
+        /// This is a newtype wrapper around any [`RealField`](simba::scalar::RealField) scalar,
+        /// defaulting to [`f64`].
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        pub struct $unit_name<T = f64>(pub T);
+
+        $crate::units::macros::paste::paste! {
+            $(
+                #[doc = "A unit of measurement, this is an alias for [`" $unit_name "`]."]
+                pub type $unit_alias<T = f64> = $unit_name<T>;
+            )*
+        }
+
+        $crate::unit_real!($unit_name);
     };
-    ($unit_name:ident : int) => {
+    ($unit_name:ident : int $(= $symbol:literal $(, $text_alias:literal)*)?) => {
         /// A unit of measurement.
         /// This is a newtype wrapper around a [`i64`].
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -93,8 +268,11 @@ macro_rules! unit {
         $crate::unit_num!($unit_name : i64);
         $crate::unit_integer!($unit_name);
         $crate::unit_structure!($unit_name : i64);
+        $crate::unit_value!($unit_name : i64);
+        $crate::unit_str!($unit_name : i64);
+        $crate::unit_name!($unit_name $(= $symbol $(, $text_alias)*)?);
     };
-    ($unit_name:ident : uint) => {
+    ($unit_name:ident : uint $(= $symbol:literal $(, $text_alias:literal)*)?) => {
         /// A unit of measurement.
         /// This is a newtype wrapper around a [`u64`].
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -122,6 +300,9 @@ macro_rules! unit {
         $crate::unit_num!($unit_name : u64);
         $crate::unit_uinteger!($unit_name);
         $crate::unit_structure!($unit_name : u64);
+        $crate::unit_value!($unit_name : u64);
+        $crate::unit_str!($unit_name : u64);
+        $crate::unit_name!($unit_name $(= $symbol $(, $text_alias)*)?);
     };
 }
 
@@ -141,8 +322,102 @@ macro_rules! unit {
 ///     degree.to_radians()
 /// }
 /// ````
+///
+/// Units declared with [`unit_quantity!`](crate::unit_quantity) should convert with `~ si`,
+/// this goes through the SI base unit using the exact scales of both units
+/// so every conversion in a family takes the same path.
+/// Conversions that are not a pure scale can pass the inverse function after the forward function.
+/// ```
+/// use frclib_core::{unit_conversion, unit_quantity, unit};
+/// use frclib_core::units::quantity::dims;
+///
+/// unit!(Yard: float);
+/// unit!(Fathom: float);
+/// unit!(Celsius: float);
+/// unit!(Kelvin: float);
+///
+/// unit_quantity!(Yard: dims::Length = 0.9144);
+/// unit_quantity!(Fathom: dims::Length = 1.8288);
+///
+/// unit_conversion!(Yard(float) <-> Fathom(float) ~ si);
+/// unit_conversion!(Celsius(float) <-> Kelvin(float) ~ celsius_to_kelvin, kelvin_to_celsius);
+///
+/// fn celsius_to_kelvin(celsius: f64) -> f64 {
+///     celsius + 273.15
+/// }
+///
+/// fn kelvin_to_celsius(kelvin: f64) -> f64 {
+///     kelvin - 273.15
+/// }
+///
+/// assert_eq!(Fathom::from(Yard::new(2.0)), Fathom::new(1.0));
+/// assert_eq!(Celsius::from(Kelvin::new(273.15)), Celsius::new(0.0));
+/// ```
+///
+/// Units with a [`FamilyUnit`](crate::units::FamilyUnit) map convert with `~ family`,
+/// [`unit_family!`](crate::unit_family) generates these for every pair of units it is given maps for.
+/// ```
+/// use frclib_core::units::{FamilyUnit, UnitMap};
+/// use frclib_core::{unit_conversion, unit};
+///
+/// unit!(Yard: float);
+/// unit!(Chain: float);
+///
+/// impl FamilyUnit for Yard {
+///     fn unit_map() -> UnitMap {
+///         UnitMap::IDENTITY
+///     }
+/// }
+///
+/// impl FamilyUnit for Chain {
+///     fn unit_map() -> UnitMap {
+///         UnitMap::linear(22.0)
+///     }
+/// }
+///
+/// unit_conversion!(Yard(float) <-> Chain(float) ~ family);
+///
+/// assert_eq!(Yard::from(Chain::new(2.0)), Yard::new(44.0));
+/// ```
 #[macro_export]
 macro_rules! unit_conversion {
+    ($unit_a:ident (float) <-> $unit_b:ident (float) ~ family) => {
+        $crate::inner_unit_conversion!(
+            $unit_a f64 | $unit_b f64
+            : |value: f64| $crate::units::UnitMap::convert(
+                value,
+                <$unit_a as $crate::units::FamilyUnit>::unit_map(),
+                <$unit_b as $crate::units::FamilyUnit>::unit_map(),
+            ),
+            |value: f64| $crate::units::UnitMap::convert(
+                value,
+                <$unit_b as $crate::units::FamilyUnit>::unit_map(),
+                <$unit_a as $crate::units::FamilyUnit>::unit_map(),
+            )
+        );
+    };
+    ($unit_a:ident (float) <-> $unit_b:ident (float) ~ si) => {
+        $crate::inner_unit_conversion!(
+            $unit_a f64 | $unit_b f64
+            : |value: f64| $crate::units::quantity::rescale(
+                value,
+                <$unit_a as $crate::units::quantity::UnitQuantity>::SCALE,
+                <$unit_b as $crate::units::quantity::UnitQuantity>::SCALE,
+            ),
+            |value: f64| $crate::units::quantity::rescale(
+                value,
+                <$unit_b as $crate::units::quantity::UnitQuantity>::SCALE,
+                <$unit_a as $crate::units::quantity::UnitQuantity>::SCALE,
+            )
+        );
+    };
+    ($unit_a:ident ( $unit_a_type:ident ) <-> $unit_b:ident ( $unit_b_type:ident ) ~ $conv_fn:ident, $rev_fn:ident ) => {
+        $crate::inner_unit_conversion!(
+            $unit_a $crate::complex_type_name!($unit_a_type)
+            | $unit_b $crate::complex_type_name!($unit_b_type)
+            : $conv_fn, $rev_fn
+        );
+    };
     ($unit_a:ident ( $unit_a_type:ident ) <-> $unit_b:ident ( $unit_b_type:ident ) ~ $conv_fn:ident ) => {
         $crate::inner_unit_conversion!(
             $unit_a $crate::complex_type_name!($unit_a_type)
@@ -187,7 +462,73 @@ macro_rules! unit_conversion {
     };
 }
 
+/// A macro for converting a unit between its scalar backings.
+///
+/// This links an [`f64`] unit to the [`f32`] or [`RealField`](simba::scalar::RealField) backed
+/// version of the same unit, the backed unit then joins every family the [`f64`] unit is in.
+///
+/// # Example
+/// ```
+/// use frclib_core::{unit_backing, unit_family, unit};
+///
+/// unit!(Meter: float);
+/// unit!(MeterF32: f32);
+/// unit!(MeterReal: real);
+///
+/// unit_family!(Distance(Meter): MeterF32);
+/// unit_backing!(Meter(float) <-> MeterF32(f32));
+/// unit_backing!(Meter(float) <-> MeterReal(real));
+///
+/// assert_eq!(Meter::from(MeterF32(1.5)), Meter(1.5));
+/// assert_eq!(MeterF32(2.0).conv::<MeterReal<f32>>(), MeterReal(2.0));
+/// ```
+#[macro_export]
+macro_rules! unit_backing {
+    ($unit_a:ident(float) <-> $unit_b:ident(f32)) => {
+        impl From<$unit_a> for $unit_b {
+            #[allow(clippy::cast_possible_truncation)]
+            fn from(value: $unit_a) -> Self {
+                Self(value.0 as f32)
+            }
+        }
+
+        impl From<$unit_b> for $unit_a {
+            fn from(value: $unit_b) -> Self {
+                Self(f64::from(value.0))
+            }
+        }
+    };
+    ($unit_a:ident(float) <-> $unit_b:ident(real)) => {
+        impl<T: simba::scalar::RealField> From<$unit_a> for $unit_b<T> {
+            fn from(value: $unit_a) -> Self {
+                Self::from(value.0)
+            }
+        }
+
+        impl<T: simba::scalar::RealField> From<$unit_b<T>> for $unit_a {
+            fn from(value: $unit_b<T>) -> Self {
+                Self(f64::from(value))
+            }
+        }
+    };
+    ($unit_a:ident(f32) <-> $unit_b:ident(real)) => {
+        impl<T: simba::scalar::RealField> From<$unit_a> for $unit_b<T> {
+            fn from(value: $unit_a) -> Self {
+                Self::from(f64::from(value.0))
+            }
+        }
+
+        impl<T: simba::scalar::RealField> From<$unit_b<T>> for $unit_a {
+            #[allow(clippy::cast_possible_truncation)]
+            fn from(value: $unit_b<T>) -> Self {
+                Self(f64::from(value) as f32)
+            }
+        }
+    };
+}
+
 /// A macro for defining a unit family.
+///
 /// Unit families allow all units to fall under a single trait.
 /// This allows for easy conversion between units of the same family
 /// and allows for functions to be generic over all units of a family.
@@ -218,9 +559,77 @@ macro_rules! unit_conversion {
 ///     degree_to_rotation(radian.to_degrees())
 /// }
 /// ````
+///
+/// Instead of writing every conversion, each unit can give its map to the standard unit,
+/// either a scale or a `(scale, offset)` pair where the standard value is `value * scale + offset`.
+/// Every pairwise conversion is then generated through the standard unit.
+/// Adding `in` and a dimension also declares the [`unit_quantity!`](crate::unit_quantity) of every unit,
+/// which needs the standard unit to be the SI unit and every map to be a pure scale.
+///
+/// Every family can parse text in any of its units with `parse`,
+/// an optional `scale` lists the units `display_scaled` picks from, smallest first.
+/// More scales can follow separated by `|`, the units in them display within their own scale.
+/// ```
+/// use frclib_core::{unit_family, unit};
+/// use frclib_core::units::quantity::dims;
+///
+/// unit!(Gram: float = "g");
+/// unit!(Kilogram: float = "kg");
+/// unit!(Tonne: float = "t");
+/// unit!(Celsius: float = "°C");
+/// unit!(Fahrenheit: float = "°F");
+///
+/// unit_family!(Mass(Kilogram) in dims::Mass: Gram = 0.001, Tonne = 1000.0; scale: Gram, Kilogram, Tonne);
+/// unit_family!(Temperature(Celsius): Fahrenheit = (5.0 / 9.0, -160.0 / 9.0));
+///
+/// assert_eq!(Tonne::from(Gram::new(2_000_000.0)), Tonne::new(2.0));
+/// assert_eq!(Gram::new(1500.0).to_kilogram(), Kilogram::new(1.5));
+/// assert!((Fahrenheit::from(Celsius::new(100.0)).value() - 212.0).abs() < 1e-9);
+///
+/// assert_eq!(Gram::parse("1.5 kg"), Ok(Gram(1500.0)));
+/// assert_eq!(Gram(1500.0).display_scaled().to_string(), "1.5 kg");
+/// ```
 #[macro_export]
 macro_rules! unit_family {
-    ($family_name:ident ( $standard:ident ): $($unit_name:ident),*) => {
+    ($family_name:ident ( $standard:ident ): $($unit_name:ident),* $(; scale: $($($scale:ident),+)|+)?) => {
+        $crate::unit_family!(@trait $family_name($standard): $($unit_name),* $(; scale: $($($scale),+)|+)?);
+    };
+    ($family_name:ident ( $standard:ident ) in $dim:ty : $($unit_name:ident = $map:expr),* $(; scale: $($($scale:ident),+)|+)?) => {
+        $crate::unit_family!(@graph $standard in $dim: $($unit_name = $map),*);
+        $crate::unit_family!(@trait $family_name($standard): $($unit_name),* $(; scale: $($($scale),+)|+)?);
+    };
+    ($family_name:ident ( $standard:ident ): $($unit_name:ident = $map:expr),* $(; scale: $($($scale:ident),+)|+)?) => {
+        $crate::unit_family!(@graph $standard: $($unit_name = $map),*);
+        $crate::unit_family!(@trait $family_name($standard): $($unit_name),* $(; scale: $($($scale),+)|+)?);
+    };
+    (@graph $standard:ident in $dim:ty : $($unit_name:ident = $map:expr),*) => {
+        $crate::unit_quantity!($standard: $dim = 1.0);
+        $($crate::unit_quantity!($unit_name: $dim = $map);)*
+        $crate::unit_family!(@graph $standard: $($unit_name = $map),*);
+    };
+    (@graph $standard:ident : $($unit_name:ident = $map:expr),*) => {
+        impl $crate::units::FamilyUnit for $standard {
+            #[inline]
+            fn unit_map() -> $crate::units::UnitMap {
+                $crate::units::UnitMap::IDENTITY
+            }
+        }
+        $(
+            impl $crate::units::FamilyUnit for $unit_name {
+                #[inline]
+                fn unit_map() -> $crate::units::UnitMap {
+                    $crate::units::UnitMap::from($map)
+                }
+            }
+        )*
+        $crate::unit_family!(@pairs $standard $(, $unit_name)*);
+    };
+    (@pairs $first:ident $(, $rest:ident)*) => {
+        $($crate::unit_conversion!($first(float) <-> $rest(float) ~ family);)*
+        $crate::unit_family!(@pairs $($rest),*);
+    };
+    (@pairs) => {};
+    (@trait $family_name:ident ( $standard:ident ): $($unit_name:ident),* $(; scale: $($($scale:ident),+)|+)?) => {
         $crate::units::macros::paste::paste! {
             #[doc = "A family of units representing an `" $standard "` measurement."]
             #[doc = ""]
@@ -234,9 +643,29 @@ macro_rules! unit_family {
                     self.into()
                 }
 
+                #[doc = "Converts this unit to any other unit of the family."]
+                #[inline]
                 fn conv<U: $family_name>(self) -> U {
                     U::from(self.standard())
                 }
+
+                #[doc = "Converts this unit to `" $standard "`."]
+                #[doc = "This is the same as [`standard`](#method.standard)."]
+                #[inline]
+                fn [<to_ $standard:snake>](self) -> $standard {
+                    self.standard()
+                }
+
+                $(
+                    #[doc = "Converts this unit to `" $unit_name "`."]
+                    #[inline]
+                    fn [<to_ $unit_name:snake>](self) -> $unit_name {
+                        $unit_name::from(self.standard())
+                    }
+                )*
+
+                $crate::unit_family_value!($family_name($standard): $($unit_name),*);
+                $crate::unit_family_text!($family_name($standard): $($unit_name),* $(; scale: $($($scale),+)|+)?);
             }
         }
 
@@ -245,6 +674,59 @@ macro_rules! unit_family {
             T: Into<$standard> + From<$standard> + Copy,
         {
         }
+
+        $crate::unit_family_parse!($family_name($standard): $($unit_name),*);
+        $($crate::unit_family_text!(@scales $standard; $($($scale),+)|+);)?
+    };
+}
+
+/// A macro for adding a unit to a family declared with maps in [`unit_family!`](crate::unit_family),
+/// this works the same way from downstream crates.
+///
+/// The unit gives its map to the standard unit and gets conversions to and from the standard unit,
+/// which makes it part of the family with `conv` and the `to_*` methods.
+/// Direct conversions with other units of the family can be listed after a `;`.
+///
+/// # Example
+/// ```
+/// use frclib_core::{unit, unit_member};
+/// use frclib_core::units::length::{Distance, Foot, Meter};
+///
+/// unit!(Furlong: float = "fur");
+///
+/// unit_member!(Meter: Furlong = 201.168; Foot);
+///
+/// assert_eq!(Meter::from(Furlong::new(1.0)), Meter::new(201.168));
+/// assert_eq!(Foot::from(Furlong::new(1.0)), Foot::new(660.0));
+/// assert_eq!(Furlong::new(0.5).to_feet(), Foot::new(330.0));
+/// ```
+#[macro_export]
+macro_rules! unit_member {
+    ($standard:ident : $unit_name:ident = $map:expr $(; $($other:ident),+)?) => {
+        impl $crate::units::FamilyUnit for $unit_name {
+            #[inline]
+            fn unit_map() -> $crate::units::UnitMap {
+                $crate::units::UnitMap::from($map)
+            }
+        }
+        $crate::unit_conversion!($standard(float) <-> $unit_name(float) ~ family);
+        $($($crate::unit_conversion!($other(float) <-> $unit_name(float) ~ family);)+)?
+
+        impl $crate::units::ParseFamily for $unit_name {
+            #[inline]
+            fn parse_family(text: &str) -> Result<Self, $crate::units::UnitParseError> {
+                let (value, suffix) = $crate::units::split_number::<f64>(text)?;
+                Self::from_family(value, suffix)
+            }
+
+            #[inline]
+            fn from_family(value: f64, suffix: &str) -> Result<Self, $crate::units::UnitParseError> {
+                if $crate::units::matches_suffix::<Self>(suffix) {
+                    return Ok(Self::from(value));
+                }
+                <$standard as $crate::units::ParseFamily>::from_family(value, suffix).map(Self::from)
+            }
+        }
     };
 }
 
@@ -407,11 +889,74 @@ macro_rules! unit_dim_analysis {
     };
 }
 
+/// A macro for declaring a unit as a scaled view of a [`Quantity`](crate::units::quantity::Quantity).
+///
+/// The scale is how many SI base units one of the unit is,
+/// this gives the unit access to every product and quotient of [`Quantity`](crate::units::quantity::Quantity)
+/// through [`UnitQuantity::quantity`](crate::units::quantity::UnitQuantity::quantity).
+/// Products of the unit itself still need a [`unit_dim_analysis!`](crate::unit_dim_analysis).
+///
+/// # Example
+/// ```
+/// use frclib_core::{unit, unit_quantity};
+/// use frclib_core::units::quantity::{dims, UnitQuantity};
+///
+/// unit!(Yard: float);
+/// unit!(Minute: float);
+///
+/// unit_quantity!(Yard: dims::Length = 0.9144);
+/// unit_quantity!(Minute: dims::Time = 60.0);
+///
+/// let speed = Yard::new(60.0).quantity() / Minute::new(1.0).quantity();
+/// assert!((speed.value() - 0.9144).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! unit_quantity {
+    ($unit_name:ident : $dim:ty = $scale:expr) => {
+        impl $crate::units::quantity::UnitQuantity for $unit_name {
+            type Dim = $dim;
+            const SCALE: f64 = $scale;
+
+            #[inline]
+            fn quantity(self) -> $crate::units::quantity::Quantity<$dim> {
+                $crate::units::quantity::Quantity::new(
+                    self.0 * <Self as $crate::units::quantity::UnitQuantity>::SCALE,
+                )
+            }
+
+            #[inline]
+            fn from_quantity(quantity: $crate::units::quantity::Quantity<$dim>) -> Self {
+                Self(quantity.value() / <Self as $crate::units::quantity::UnitQuantity>::SCALE)
+            }
+        }
+
+        impl From<$unit_name> for $crate::units::quantity::Quantity<$dim> {
+            #[inline]
+            fn from(value: $unit_name) -> Self {
+                $crate::units::quantity::UnitQuantity::quantity(value)
+            }
+        }
+
+        impl From<$crate::units::quantity::Quantity<$dim>> for $unit_name {
+            #[inline]
+            fn from(value: $crate::units::quantity::Quantity<$dim>) -> Self {
+                $crate::units::quantity::UnitQuantity::from_quantity(value)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod test {
     unit!(Degree: float);
     unit!(Millisecond: int);
     unit!(Microsecond: uint);
+    unit!(DegreeF32: f32);
+    unit!(DegreeReal: real);
+
+    unit_backing!(Degree(float) <-> DegreeF32(f32));
+    unit_backing!(Degree(float) <-> DegreeReal(real));
+    unit_backing!(DegreeF32(f32) <-> DegreeReal(real));
 
     #[test]
     fn ops() {
@@ -427,4 +972,18 @@ mod test {
         let new_micro = 1u64 + micro;
         assert_eq!(new_micro, Microsecond(2));
     }
+
+    #[test]
+    fn backings() {
+        let deg = DegreeF32(1.5) + 1.0f32;
+        assert_eq!(deg, DegreeF32(2.5));
+        assert_eq!(Degree::from(deg), Degree(2.5));
+        assert_eq!(DegreeF32::from(Degree(0.1)), DegreeF32(0.1));
+
+        let real: DegreeReal<f32> = DegreeF32(3.0).into();
+        assert_eq!(real * 2.0 - DegreeReal(1.0), DegreeReal(5.0));
+        assert_eq!(real.cast::<f64>(), DegreeReal(3.0));
+        assert_eq!(DegreeF32::from(DegreeReal(4.0f64)), DegreeF32(4.0));
+        assert_eq!(Degree::from(real), Degree(3.0));
+    }
 }
@@ -69,9 +69,12 @@ macro_rules! forward_into_args {
 #[macro_export]
 macro_rules! unit_float {
     ($unit_name:ident) => {
+        $crate::unit_float!($unit_name : f64);
+    };
+    ($unit_name:ident : $float:ident) => {
         impl num::traits::NumCast for $unit_name {
             fn from<T: num::traits::ToPrimitive>(n: T) -> Option<Self> {
-                n.to_f64().map(Self)
+                <$float as num::traits::NumCast>::from(n).map(Self)
             }
         }
         impl num::traits::Float for $unit_name {
@@ -87,67 +90,67 @@ macro_rules! unit_float {
                 Self(self.0.powi(n))
             }
             $crate::forward_into! {
-                f64::floor as floor(self) -> Self;
-                f64::ceil as ceil(self) -> Self;
-                f64::round as round(self) -> Self;
-                f64::trunc as trunc(self) -> Self;
-                f64::abs as abs(self) -> Self;
-                f64::sqrt as sqrt(self) -> Self;
-                f64::exp as exp(self) -> Self;
-                f64::exp2 as exp2(self) -> Self;
-                f64::ln as ln(self) -> Self;
-                f64::log2 as log2(self) -> Self;
-                f64::log10 as log10(self) -> Self;
-                f64::cbrt as cbrt(self) -> Self;
-                f64::sin as sin(self) -> Self;
-                f64::cos as cos(self) -> Self;
-                f64::tan as tan(self) -> Self;
-                f64::asin as asin(self) -> Self;
-                f64::acos as acos(self) -> Self;
-                f64::atan as atan(self) -> Self;
-                f64::exp_m1 as exp_m1(self) -> Self;
-                f64::ln_1p as ln_1p(self) -> Self;
-                f64::sinh as sinh(self) -> Self;
-                f64::cosh as cosh(self) -> Self;
-                f64::tanh as tanh(self) -> Self;
-                f64::asinh as asinh(self) -> Self;
-                f64::acosh as acosh(self) -> Self;
-                f64::atanh as atanh(self) -> Self;
-                f64::to_degrees as to_degrees(self) -> Self;
-                f64::to_radians as to_radians(self) -> Self;
-                f64::fract as fract(self) -> Self;
-                f64::recip as recip(self) -> Self;
-                f64::signum as signum(self) -> Self;
+                $float::floor as floor(self) -> Self;
+                $float::ceil as ceil(self) -> Self;
+                $float::round as round(self) -> Self;
+                $float::trunc as trunc(self) -> Self;
+                $float::abs as abs(self) -> Self;
+                $float::sqrt as sqrt(self) -> Self;
+                $float::exp as exp(self) -> Self;
+                $float::exp2 as exp2(self) -> Self;
+                $float::ln as ln(self) -> Self;
+                $float::log2 as log2(self) -> Self;
+                $float::log10 as log10(self) -> Self;
+                $float::cbrt as cbrt(self) -> Self;
+                $float::sin as sin(self) -> Self;
+                $float::cos as cos(self) -> Self;
+                $float::tan as tan(self) -> Self;
+                $float::asin as asin(self) -> Self;
+                $float::acos as acos(self) -> Self;
+                $float::atan as atan(self) -> Self;
+                $float::exp_m1 as exp_m1(self) -> Self;
+                $float::ln_1p as ln_1p(self) -> Self;
+                $float::sinh as sinh(self) -> Self;
+                $float::cosh as cosh(self) -> Self;
+                $float::tanh as tanh(self) -> Self;
+                $float::asinh as asinh(self) -> Self;
+                $float::acosh as acosh(self) -> Self;
+                $float::atanh as atanh(self) -> Self;
+                $float::to_degrees as to_degrees(self) -> Self;
+                $float::to_radians as to_radians(self) -> Self;
+                $float::fract as fract(self) -> Self;
+                $float::recip as recip(self) -> Self;
+                $float::signum as signum(self) -> Self;
             }
             $crate::forward! {
-                f64::is_nan as is_nan(self) -> bool;
-                f64::is_infinite as is_infinite(self) -> bool;
-                f64::is_finite as is_finite(self) -> bool;
-                f64::is_normal as is_normal(self) -> bool;
-                f64::classify as classify(self) -> std::num::FpCategory;
-                f64::integer_decode as integer_decode(self) -> (u64, i16, i8);
-                f64::is_sign_positive as is_sign_positive(self) -> bool;
-                f64::is_sign_negative as is_sign_negative(self) -> bool;
+                $float::is_nan as is_nan(self) -> bool;
+                $float::is_infinite as is_infinite(self) -> bool;
+                $float::is_finite as is_finite(self) -> bool;
+                $float::is_normal as is_normal(self) -> bool;
+                $float::classify as classify(self) -> std::num::FpCategory;
+                $float::integer_decode as integer_decode(self) -> (u64, i16, i8);
+                $float::is_sign_positive as is_sign_positive(self) -> bool;
+                $float::is_sign_negative as is_sign_negative(self) -> bool;
             }
             $crate::forward_into_args! {
-                f64::mul_add as mul_add(self, a: Self, b: Self) -> Self;
-                f64::log as log(self, base: Self) -> Self;
-                f64::hypot as hypot(self, other: Self) -> Self;
-                f64::atan2 as atan2(self, other: Self) -> Self;
-                f64::copysign as copysign(self, sign: Self) -> Self;
-                f64::min as min(self, other: Self) -> Self;
-                f64::max as max(self, other: Self) -> Self;
-                f64::powf as powf(self, n: Self) -> Self;
+                $float::mul_add as mul_add(self, a: Self, b: Self) -> Self;
+                $float::log as log(self, base: Self) -> Self;
+                $float::hypot as hypot(self, other: Self) -> Self;
+                $float::atan2 as atan2(self, other: Self) -> Self;
+                $float::copysign as copysign(self, sign: Self) -> Self;
+                $float::min as min(self, other: Self) -> Self;
+                $float::max as max(self, other: Self) -> Self;
+                $float::powf as powf(self, n: Self) -> Self;
             }
             $crate::constant! {
-                infinity() -> $unit_name(f64::INFINITY);
-                neg_infinity() -> $unit_name(f64::NEG_INFINITY);
-                nan() -> $unit_name(f64::NAN);
+                infinity() -> $unit_name($float::INFINITY);
+                neg_infinity() -> $unit_name($float::NEG_INFINITY);
+                nan() -> $unit_name($float::NAN);
                 neg_zero() -> $unit_name(-0.0);
-                min_value() -> $unit_name(f64::MIN);
-                min_positive_value() -> $unit_name(f64::MIN_POSITIVE);
-                epsilon() -> $unit_name(f64::EPSILON);
-                max_value() -> $unit_name(f64::MAX);
+                min_value() -> $unit_name($float::MIN);
+                min_positive_value() -> $unit_name($float::MIN_POSITIVE);
+                epsilon() -> $unit_name($float::EPSILON);
+                max_value() -> $unit_name($float::MAX);
             }
         }
     };
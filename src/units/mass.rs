@@ -1,39 +1,17 @@
-use crate::{unit, unit_conversion, unit_family};
-
-unit!(Kilogram: float);
-unit!(Gram: float);
-unit!(Pound: float);
-unit!(Ounce: float);
-
-unit_conversion!(Kilogram(float) <-> Gram(float) ~ kilogram_to_gram);
-unit_conversion!(Kilogram(float) <-> Pound(float) ~ kilogram_to_pound);
-unit_conversion!(Kilogram(float) <-> Ounce(float) ~ kilogram_to_ounce);
-unit_conversion!(Gram(float) <-> Pound(float) ~ gram_to_pound);
-unit_conversion!(Gram(float) <-> Ounce(float) ~ gram_to_ounce);
-unit_conversion!(Pound(float) <-> Ounce(float) ~ pound_to_ounce);
-
-unit_family!(Mass(Kilogram): Gram, Pound, Ounce);
-
-fn kilogram_to_gram(kilogram: f64) -> f64 {
-    kilogram * 1000.0
-}
-
-fn kilogram_to_pound(kilogram: f64) -> f64 {
-    kilogram * 2.20462
-}
-
-fn kilogram_to_ounce(kilogram: f64) -> f64 {
-    kilogram * 35.274
-}
-
-fn gram_to_pound(gram: f64) -> f64 {
-    kilogram_to_pound(gram / 1000.0)
-}
-
-fn gram_to_ounce(gram: f64) -> f64 {
-    kilogram_to_ounce(gram / 1000.0)
-}
-
-fn pound_to_ounce(pound: f64) -> f64 {
-    kilogram_to_ounce(pound / 2.20462)
-}
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+/// The international avoirdupois pound in kilograms, exact by definition
+pub(crate) const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+unit!(Kilogram: float = "kg");
+unit!(Gram: float = "g");
+unit!(Pound: float = "lb", "lbs");
+unit!(Ounce: float = "oz");
+
+unit_family!(Mass(Kilogram) in dims::Mass:
+    Gram = 0.001,
+    Pound = KILOGRAMS_PER_POUND,
+    Ounce = 0.028_349_523_125;
+    scale: Gram, Kilogram
+);
@@ -0,0 +1,224 @@
+//! Unit values that carry their own uncertainty.
+//!
+//! A [`Measured`] is a value with a standard deviation in the same unit,
+//! or in its delta unit for `point` units like [`Celsius`](super::temperature::Celsius).
+//! Arithmetic on measured values propagates the uncertainty to first order assuming the
+//! operands are independent, and products and quotients follow the same
+//! [`unit_dim_analysis!`](crate::unit_dim_analysis) relations as the bare units.
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::length::{Foot, Meter};
+//! use frclib_core::units::linear_velocity::MetersPerSecond;
+//! use frclib_core::units::measured::Measured;
+//! use frclib_core::units::time::Second;
+//!
+//! let distance = Measured::new(Meter::new(3.0), Meter::new(0.03));
+//! let time = Measured::new(Second::new(2.0), Second::new(0.02));
+//! let speed: Measured<MetersPerSecond> = distance / time;
+//! assert!((speed.std_dev().value() - 1.5 * 0.01 * 2f64.sqrt()).abs() < 1e-12);
+//!
+//! let feet = distance.convert::<Foot>();
+//! assert!((feet.std_dev().value() - 0.03 / 0.3048).abs() < 1e-12);
+//! ```
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::quantity::rescale;
+use super::FamilyUnit;
+
+/// A unit a [`Measured`] can be taken in,
+/// its standard deviation is in the unit of the difference between two values
+pub trait MeasuredUnit: Copy + Into<f64> + From<f64> + Sub<Output = Self::Delta> {
+    /// The unit of the difference between two values,
+    /// the unit itself for everything except `point` units
+    type Delta: Copy + Into<f64> + From<f64>;
+}
+
+impl<T> MeasuredUnit for T
+where
+    T: Copy + Into<f64> + From<f64> + Sub,
+    <T as Sub>::Output: Copy + Into<f64> + From<f64>,
+{
+    type Delta = <T as Sub>::Output;
+}
+
+/// A value of `U` with a standard deviation in its delta unit, see the [module docs](self).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Measured<U: MeasuredUnit> {
+    value: U,
+    std_dev: U::Delta,
+}
+
+impl<U: MeasuredUnit> Measured<U> {
+    /// Creates a measured value with the given standard deviation,
+    /// the sign of the standard deviation is ignored
+    #[must_use]
+    pub fn new(value: U, std_dev: impl Into<U::Delta>) -> Self {
+        Self {
+            value,
+            std_dev: U::Delta::from(std_dev.into().into().abs()),
+        }
+    }
+
+    /// Creates a measured value from a variance in the square of the unit of `value`
+    #[must_use]
+    pub fn from_variance(value: U, variance: f64) -> Self {
+        Self::new(value, U::Delta::from(variance.sqrt()))
+    }
+
+    /// Creates a value without any uncertainty
+    #[must_use]
+    pub fn exact(value: U) -> Self {
+        Self::new(value, U::Delta::from(0.0))
+    }
+
+    /// The measured value
+    #[must_use]
+    pub const fn value(self) -> U {
+        self.value
+    }
+
+    /// The standard deviation of the value
+    #[must_use]
+    pub const fn std_dev(self) -> U::Delta {
+        self.std_dev
+    }
+
+    /// The variance of the value, in the square of the unit of the value
+    #[must_use]
+    pub fn variance(self) -> f64 {
+        self.std_dev.into().powi(2)
+    }
+
+    /// Converts the value and its uncertainty to another unit of the same family,
+    /// the standard deviation is scaled by the ratio of the units and ignores their offsets
+    #[must_use]
+    pub fn convert<V>(self) -> Measured<V>
+    where
+        U: Into<V> + FamilyUnit,
+        V: MeasuredUnit + FamilyUnit,
+    {
+        let std_dev = rescale(
+            self.std_dev.into(),
+            U::unit_map().scale,
+            V::unit_map().scale,
+        );
+        Measured::new(self.value.into(), V::Delta::from(std_dev))
+    }
+
+    /// Combines two independent measurements of the same value into the
+    /// inverse-variance weighted estimate, which is never less certain than either
+    #[must_use]
+    pub fn fuse(self, other: Self) -> Self {
+        let (a, b) = (self.variance(), other.variance());
+        if a <= 0.0 {
+            return self;
+        }
+        if b <= 0.0 {
+            return other;
+        }
+        let (x, y): (f64, f64) = (self.value.into(), other.value.into());
+        let value = x.mul_add(b, y * a) / (a + b);
+        Self::from_variance(U::from(value), a * b / (a + b))
+    }
+}
+
+impl<U: MeasuredUnit> From<U> for Measured<U> {
+    fn from(value: U) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl<A, B, C> Add<Measured<B>> for Measured<A>
+where
+    A: MeasuredUnit + Add<B, Output = C>,
+    B: MeasuredUnit,
+    C: MeasuredUnit,
+    A::Delta: Into<C::Delta>,
+    B::Delta: Into<C::Delta>,
+{
+    type Output = Measured<C>;
+    #[inline]
+    fn add(self, rhs: Measured<B>) -> Self::Output {
+        let (a, b): (C::Delta, C::Delta) = (self.std_dev.into(), rhs.std_dev.into());
+        Measured::new(
+            self.value + rhs.value,
+            C::Delta::from(a.into().hypot(b.into())),
+        )
+    }
+}
+
+impl<A, B, C> Sub<Measured<B>> for Measured<A>
+where
+    A: MeasuredUnit + Sub<B, Output = C>,
+    B: MeasuredUnit,
+    C: MeasuredUnit,
+    A::Delta: Into<C::Delta>,
+    B::Delta: Into<C::Delta>,
+{
+    type Output = Measured<C>;
+    #[inline]
+    fn sub(self, rhs: Measured<B>) -> Self::Output {
+        let (a, b): (C::Delta, C::Delta) = (self.std_dev.into(), rhs.std_dev.into());
+        Measured::new(
+            self.value - rhs.value,
+            C::Delta::from(a.into().hypot(b.into())),
+        )
+    }
+}
+
+impl<U: MeasuredUnit + Neg<Output = U>> Neg for Measured<U> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, self.std_dev)
+    }
+}
+
+impl<U: MeasuredUnit + Mul<f64, Output = U>> Mul<f64> for Measured<U> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, U::Delta::from(self.std_dev.into() * rhs))
+    }
+}
+
+impl<U: MeasuredUnit + Div<f64, Output = U>> Div<f64> for Measured<U> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, U::Delta::from(self.std_dev.into() / rhs))
+    }
+}
+
+impl<A, B, C> Mul<Measured<B>> for Measured<A>
+where
+    A: MeasuredUnit + Mul<B, Output = C>,
+    B: MeasuredUnit,
+    C: MeasuredUnit,
+{
+    type Output = Measured<C>;
+    #[inline]
+    fn mul(self, rhs: Measured<B>) -> Self::Output {
+        let (a, b) = (self.value.into(), rhs.value.into());
+        let std_dev = (b * self.std_dev.into()).hypot(a * rhs.std_dev.into());
+        Measured::new(self.value * rhs.value, C::Delta::from(std_dev))
+    }
+}
+
+impl<A, B, C> Div<Measured<B>> for Measured<A>
+where
+    A: MeasuredUnit + Div<B, Output = C>,
+    B: MeasuredUnit,
+    C: MeasuredUnit,
+{
+    type Output = Measured<C>;
+    #[inline]
+    fn div(self, rhs: Measured<B>) -> Self::Output {
+        let (a, b) = (self.value.into(), rhs.value.into());
+        let std_dev = (self.std_dev.into() / b).hypot(a * rhs.std_dev.into() / (b * b));
+        Measured::new(self.value / rhs.value, C::Delta::from(std_dev))
+    }
+}
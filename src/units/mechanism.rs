@@ -0,0 +1,236 @@
+//! Composable transforms between the positions, velocities and accelerations of a mechanism.
+//!
+//! A [`Mechanism`] maps an input position to an output position, like a motor shaft angle
+//! to the height of an elevator carriage. Every mechanism here is affine, the velocity and
+//! acceleration are mapped by the same scale as the position without the offset,
+//! so all three are always consistent with each other.
+//!
+//! Mechanisms are chained with [`Mechanism::chain`] and undone with [`Mechanism::inverse`].
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::angle::Degree;
+//! use frclib_core::units::angular_velocity::RotationPerMin;
+//! use frclib_core::units::frc::GearRatio;
+//! use frclib_core::units::length::Inch;
+//! use frclib_core::units::mechanism::{Mechanism, Offset, Radius};
+//!
+//! // a 10:1 gearbox driving a 2 inch radius pulley
+//! let drive = GearRatio::new(10.0).chain(Radius::new(Inch::new(2.0)));
+//! let speed = drive.velocity(RotationPerMin::new(6000.0));
+//! assert!((speed.value() - 20.0 * std::f64::consts::PI * 0.0508).abs() < 1e-12);
+//!
+//! // a 50:1 arm that starts resting at -30°
+//! let arm = GearRatio::new(50.0).chain(Offset::new(Degree::new(-30.0)));
+//! let motor = arm.inverse().position(Degree::new(60.0));
+//! assert!((Degree::from(motor).value() - 4500.0).abs() < 1e-9);
+//! ```
+
+use std::ops::Div;
+
+use super::angle::Radian;
+use super::frc::{GearRatio, Wheel};
+use super::length::{Distance, Meter};
+use super::time::Second;
+use super::UnitMap;
+
+/// A position unit of a kind of motion with its velocity and acceleration units.
+///
+/// This is implemented for every unit related to its derivatives by
+/// [`unit_dim_analysis!`](crate::unit_dim_analysis), a position divided by a [`Second`]
+/// is its velocity and a velocity divided by a [`Second`] is its acceleration.
+pub trait Kinematic: Copy + From<f64> + Into<f64> {
+    /// The velocity unit of this kind of motion
+    type Velocity: Copy + From<f64> + Into<f64>;
+    /// The acceleration unit of this kind of motion
+    type Acceleration: Copy + From<f64> + Into<f64>;
+}
+
+impl<P> Kinematic for P
+where
+    P: Copy + From<f64> + Into<f64> + Div<Second>,
+    <P as Div<Second>>::Output: Copy + From<f64> + Into<f64> + Div<Second>,
+    <<P as Div<Second>>::Output as Div<Second>>::Output: Copy + From<f64> + Into<f64>,
+{
+    type Velocity = <P as Div<Second>>::Output;
+    type Acceleration = <Self::Velocity as Div<Second>>::Output;
+}
+
+/// A transform from the motion of a mechanism's input to the motion of its output.
+pub trait Mechanism: Copy {
+    /// The position unit of the input
+    type Input: Kinematic;
+    /// The position unit of the output
+    type Output: Kinematic;
+    /// The mechanism that maps the output back to the input
+    type Inverse: Mechanism<Input = Self::Output, Output = Self::Input>;
+
+    /// How an input position in its standard unit maps to an output position
+    fn map(self) -> UnitMap;
+
+    /// The mechanism that maps the output back to the input
+    fn inverse(self) -> Self::Inverse;
+
+    /// The output position for an input position
+    fn position(self, input: impl Into<Self::Input>) -> Self::Output {
+        self.map().to_standard(input.into().into()).into()
+    }
+
+    /// The output velocity for an input velocity
+    fn velocity(
+        self,
+        input: impl Into<<Self::Input as Kinematic>::Velocity>,
+    ) -> <Self::Output as Kinematic>::Velocity {
+        (input.into().into() * self.map().scale).into()
+    }
+
+    /// The output acceleration for an input acceleration
+    fn acceleration(
+        self,
+        input: impl Into<<Self::Input as Kinematic>::Acceleration>,
+    ) -> <Self::Output as Kinematic>::Acceleration {
+        (input.into().into() * self.map().scale).into()
+    }
+
+    /// This mechanism driving `next`
+    fn chain<M: Mechanism<Input = Self::Output>>(self, next: M) -> Chain<Self, M> {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+impl Mechanism for GearRatio {
+    type Input = Radian;
+    type Output = Radian;
+    type Inverse = Self;
+
+    fn map(self) -> UnitMap {
+        UnitMap::linear(self.reduction().recip())
+    }
+
+    fn inverse(self) -> Self {
+        Self::inverse(self)
+    }
+}
+
+impl Mechanism for Wheel {
+    type Input = Radian;
+    type Output = Meter;
+    type Inverse = Inverse<Self>;
+
+    fn map(self) -> UnitMap {
+        Radius::new(self.radius()).map()
+    }
+
+    fn inverse(self) -> Inverse<Self> {
+        Inverse(self)
+    }
+}
+
+/// A radius turning angles into the distance traveled along its circumference,
+/// like a pulley, sprocket or the pivot of an arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radius(Meter);
+
+impl Radius {
+    /// Creates a radius of the given length
+    #[must_use]
+    pub fn new(radius: impl Distance) -> Self {
+        Self(radius.standard())
+    }
+
+    /// The length of the radius
+    #[must_use]
+    pub const fn length(self) -> Meter {
+        self.0
+    }
+}
+
+impl Mechanism for Radius {
+    type Input = Radian;
+    type Output = Meter;
+    type Inverse = Inverse<Self>;
+
+    fn map(self) -> UnitMap {
+        UnitMap::linear(self.0.value())
+    }
+
+    fn inverse(self) -> Inverse<Self> {
+        Inverse(self)
+    }
+}
+
+/// A constant offset of position, like the resting angle of an arm.
+///
+/// Velocities and accelerations pass through unchanged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Offset<K>(K);
+
+impl<K: Kinematic> Offset<K> {
+    /// Creates an offset that adds `offset` to every position
+    #[must_use]
+    pub fn new(offset: impl Into<K>) -> Self {
+        Self(offset.into())
+    }
+}
+
+impl<K: Kinematic> Mechanism for Offset<K> {
+    type Input = K;
+    type Output = K;
+    type Inverse = Self;
+
+    fn map(self) -> UnitMap {
+        UnitMap::affine(1.0, self.0.into())
+    }
+
+    fn inverse(self) -> Self {
+        Self(K::from(-self.0.into()))
+    }
+}
+
+/// Two mechanisms where the output of the first drives the second, see [`Mechanism::chain`].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Mechanism, B: Mechanism<Input = A::Output>> Mechanism for Chain<A, B> {
+    type Input = A::Input;
+    type Output = B::Output;
+    type Inverse = Chain<B::Inverse, A::Inverse>;
+
+    fn map(self) -> UnitMap {
+        self.first.map().then(self.second.map())
+    }
+
+    fn inverse(self) -> Self::Inverse {
+        Chain {
+            first: self.second.inverse(),
+            second: self.first.inverse(),
+        }
+    }
+}
+
+/// A mechanism driven from its output side, see [`Mechanism::inverse`].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inverse<M>(M);
+
+impl<M: Mechanism<Inverse = Self>> Mechanism for Inverse<M> {
+    type Input = M::Output;
+    type Output = M::Input;
+    type Inverse = M;
+
+    fn map(self) -> UnitMap {
+        self.0.map().inverse()
+    }
+
+    fn inverse(self) -> M {
+        self.0
+    }
+}
@@ -43,21 +43,103 @@
 
 pub mod angle;
 pub mod angular_acceleration;
+pub mod angular_jerk;
 pub mod angular_velocity;
+pub mod area;
+pub mod charge;
+pub mod constants;
+pub mod current;
+pub mod curvature;
 pub mod data;
 pub mod data_rate;
 pub mod energy;
+pub mod force;
+pub mod frc;
+pub mod frequency;
 pub mod length;
 pub mod linear_acceleration;
+pub mod linear_jerk;
 pub mod linear_velocity;
 pub mod mass;
+pub mod measured;
+pub mod mechanism;
 pub mod moment_of_inertia;
+pub mod power;
+pub mod pressure;
+pub mod quantity;
+pub mod resistance;
+pub mod tagged;
 pub mod temperature;
 pub mod time;
 pub mod torque;
+pub mod voltage;
+pub mod volume;
 
+mod conversion;
 #[doc(hidden)]
 pub mod macros;
+mod text;
+
+pub use conversion::{FamilyUnit, UnitMap};
+#[doc(hidden)]
+pub use text::{matches_suffix, split_number};
+pub use text::{ParseFamily, UnitDisplay, UnitParseError};
+
+/// Information shared by every unit type created with [`unit!`](crate::unit)
+pub trait Unit: Copy {
+    /// The name of the unit, this is the name of the rust type
+    const NAME: &'static str;
+    /// The symbol the unit is displayed with, like `m` for [`Meter`](length::Meter)
+    const SYMBOL: &'static str = Self::NAME;
+    /// Other names the unit is parsed from, matched ignoring ascii case
+    const ALIASES: &'static [&'static str] = &[];
+}
+
+/// An [`FrcValue`](crate::value::FrcValue) tagged with the name of the unit it was measured in.
+///
+/// Sending the unit name alongside the value, for example as a topic property,
+/// lets a receiver convert into whichever unit of the family it uses.
+/// Unsigned units like [`Microsecond`](time::Microsecond) are sent as
+/// [`FrcValue::Int`](crate::value::FrcValue::Int) and clamp at [`i64::MAX`].
+///
+/// # Example
+/// ```
+/// use frclib_core::units::{FrcUnitValue, length::{Distance, Foot, Meter}};
+///
+/// let sent = FrcUnitValue::new(Foot::new(1.0));
+/// assert_eq!(&*sent.unit, "Foot");
+/// let received = Meter::from_unit_value(sent).unwrap();
+/// assert!((received.value() - 0.3048).abs() < 1e-4);
+/// ```
+#[cfg(feature = "value-union")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrcUnitValue {
+    /// The value in the tagged unit
+    pub value: crate::value::FrcValue,
+    /// The name of the unit, see [`Unit::NAME`]
+    pub unit: Box<str>,
+}
+
+#[cfg(feature = "value-union")]
+impl FrcUnitValue {
+    /// Tags a unit value with the name of its unit
+    #[must_use]
+    pub fn new<U: Unit + Into<crate::value::FrcValue>>(value: U) -> Self {
+        Self {
+            value: value.into(),
+            unit: U::NAME.into(),
+        }
+    }
+
+    /// Creates a tagged value from parts, typically received over the network
+    #[must_use]
+    pub fn from_parts(value: crate::value::FrcValue, unit: impl Into<Box<str>>) -> Self {
+        Self {
+            value,
+            unit: unit.into(),
+        }
+    }
+}
 #[cfg(test)]
 #[doc(hidden)]
 mod test;
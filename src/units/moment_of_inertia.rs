@@ -1,12 +1,11 @@
-use crate::{unit, unit_conversion, unit_family};
+use crate::units::area::SQUARE_METERS_PER_SQUARE_FOOT;
+use crate::units::mass::KILOGRAMS_PER_POUND;
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
 
-unit!(KilogramSquareMeter: float);
-unit!(PoundSquareFoot: float);
+unit!(KilogramSquareMeter: float = "kg·m²", "kg*m^2");
+unit!(PoundSquareFoot: float = "lb·ft²", "lb*ft^2");
 
-unit_conversion!(KilogramSquareMeter(float) <-> PoundSquareFoot(float) ~ kilogram_square_meter_to_pound_square_foot);
-
-unit_family!(MomentOfInertia(KilogramSquareMeter): PoundSquareFoot);
-
-fn kilogram_square_meter_to_pound_square_foot(kilogram_square_meter: f64) -> f64 {
-    kilogram_square_meter * 0.204_816_143_622_5
-}
+unit_family!(MomentOfInertia(KilogramSquareMeter) in dims::MomentOfInertia:
+    PoundSquareFoot = KILOGRAMS_PER_POUND * SQUARE_METERS_PER_SQUARE_FOOT
+);
@@ -0,0 +1,19 @@
+use crate::units::current::Amp;
+use crate::units::force::Newton;
+use crate::units::linear_velocity::MetersPerSecond;
+use crate::units::quantity::dims;
+use crate::units::voltage::Volt;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(Watt | Watts: float = "W");
+unit!(Milliwatt | Milliwatts: float = "mW");
+unit!(Kilowatt | Kilowatts: float = "kW");
+
+unit_family!(Power(Watt) in dims::Power:
+    Milliwatt = 0.001,
+    Kilowatt = 1000.0;
+    scale: Milliwatt, Watt, Kilowatt
+);
+
+unit_dim_analysis!(Volt * Amp = Watt);
+unit_dim_analysis!(Newton * MetersPerSecond = Watt);
@@ -0,0 +1,25 @@
+use crate::units::area::SQUARE_METERS_PER_SQUARE_INCH;
+use crate::units::energy::Joule;
+use crate::units::force::NEWTONS_PER_POUND_FORCE;
+use crate::units::quantity::dims;
+use crate::units::volume::CubicMeter;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+/// One pound-force per square inch in pascals
+const PASCALS_PER_PSI: f64 = NEWTONS_PER_POUND_FORCE / SQUARE_METERS_PER_SQUARE_INCH;
+
+unit!(Pascal | Pascals | Pa: float = "Pa");
+unit!(Kilopascal | Kilopascals | KPa: float = "kPa");
+unit!(Psi | PoundsPerSquareInch: float = "psi");
+unit!(Bar | Bars: float = "bar");
+unit!(Atmosphere | Atmospheres | Atm: float = "atm");
+
+unit_family!(Pressure(Pascal) in dims::Pressure:
+    Kilopascal = 1000.0,
+    Psi = PASCALS_PER_PSI,
+    Bar = 100_000.0,
+    Atmosphere = 101_325.0;
+    scale: Pascal, Kilopascal
+);
+
+unit_dim_analysis!(Pascal * CubicMeter = Joule);
@@ -0,0 +1,500 @@
+//! A dimension checked quantity type.
+//!
+//! A [`Quantity`] stores its value in SI base units and tracks the exponent of every
+//! SI base dimension at the type level, so every physically valid product and quotient
+//! exists without having to be declared.
+//! Named units such as [`Meter`](crate::units::length::Meter) are scaled views of a quantity
+//! and convert to and from it through [`UnitQuantity`].
+//! Only [`Quantity`] gets these products automatically, named units multiply and divide
+//! directly only where a [`unit_dim_analysis!`](crate::unit_dim_analysis) declares it,
+//! any other product goes through [`UnitQuantity::quantity`] and back.
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::{current::Amp, power::Watt, voltage::Volt};
+//! use frclib_core::units::quantity::{dims, Quantity, UnitQuantity};
+//! use frclib_core::units::length::Foot;
+//! use frclib_core::units::time::Second;
+//!
+//! let power: Watt = (Volt::new(12.0).quantity() * Amp::new(2.0).quantity()).into();
+//! assert_eq!(power, Watt::new(24.0));
+//!
+//! let speed: Quantity<dims::Velocity> = Foot::new(10.0).quantity() / Second::new(2.0).quantity();
+//! assert!((speed.value() - 1.524).abs() < 1e-12);
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use typenum::{Diff, Integer, Sum};
+
+/// A set of SI base dimension exponents encoded as [`typenum`] integers.
+///
+/// The parameters are, in order: length, mass, time, electric current,
+/// temperature, amount of substance, luminous intensity and plane angle.
+/// Angle is not an SI base dimension but is tracked so radians and meters stay distinct.
+#[allow(clippy::type_complexity)]
+pub struct Dim<L, M, T, I, Th, N, J, A>(PhantomData<(L, M, T, I, Th, N, J, A)>);
+
+impl<L, M, T, I, Th, N, J, A> fmt::Debug for Dim<L, M, T, I, Th, N, J, A>
+where
+    Self: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dim")
+            .field("length", &Self::LENGTH)
+            .field("mass", &Self::MASS)
+            .field("time", &Self::TIME)
+            .field("current", &Self::CURRENT)
+            .field("temperature", &Self::TEMPERATURE)
+            .field("amount", &Self::AMOUNT)
+            .field("luminosity", &Self::LUMINOSITY)
+            .field("angle", &Self::ANGLE)
+            .finish()
+    }
+}
+
+/// A type level description of a physical dimension
+pub trait Dimension: 'static {
+    /// The exponent of length
+    const LENGTH: i8;
+    /// The exponent of mass
+    const MASS: i8;
+    /// The exponent of time
+    const TIME: i8;
+    /// The exponent of electric current
+    const CURRENT: i8;
+    /// The exponent of temperature
+    const TEMPERATURE: i8;
+    /// The exponent of amount of substance
+    const AMOUNT: i8;
+    /// The exponent of luminous intensity
+    const LUMINOSITY: i8;
+    /// The exponent of plane angle
+    const ANGLE: i8;
+}
+
+impl<L, M, T, I, Th, N, J, A> Dimension for Dim<L, M, T, I, Th, N, J, A>
+where
+    L: Integer + 'static,
+    M: Integer + 'static,
+    T: Integer + 'static,
+    I: Integer + 'static,
+    Th: Integer + 'static,
+    N: Integer + 'static,
+    J: Integer + 'static,
+    A: Integer + 'static,
+{
+    const LENGTH: i8 = L::I8;
+    const MASS: i8 = M::I8;
+    const TIME: i8 = T::I8;
+    const CURRENT: i8 = I::I8;
+    const TEMPERATURE: i8 = Th::I8;
+    const AMOUNT: i8 = N::I8;
+    const LUMINOSITY: i8 = J::I8;
+    const ANGLE: i8 = A::I8;
+}
+
+impl<L1, M1, T1, I1, Th1, N1, J1, A1, L2, M2, T2, I2, Th2, N2, J2, A2>
+    Mul<Dim<L2, M2, T2, I2, Th2, N2, J2, A2>> for Dim<L1, M1, T1, I1, Th1, N1, J1, A1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+    Th1: Add<Th2>,
+    N1: Add<N2>,
+    J1: Add<J2>,
+    A1: Add<A2>,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = Dim<
+        Sum<L1, L2>,
+        Sum<M1, M2>,
+        Sum<T1, T2>,
+        Sum<I1, I2>,
+        Sum<Th1, Th2>,
+        Sum<N1, N2>,
+        Sum<J1, J2>,
+        Sum<A1, A2>,
+    >;
+    fn mul(self, _: Dim<L2, M2, T2, I2, Th2, N2, J2, A2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+impl<L1, M1, T1, I1, Th1, N1, J1, A1, L2, M2, T2, I2, Th2, N2, J2, A2>
+    Div<Dim<L2, M2, T2, I2, Th2, N2, J2, A2>> for Dim<L1, M1, T1, I1, Th1, N1, J1, A1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+    Th1: Sub<Th2>,
+    N1: Sub<N2>,
+    J1: Sub<J2>,
+    A1: Sub<A2>,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = Dim<
+        Diff<L1, L2>,
+        Diff<M1, M2>,
+        Diff<T1, T2>,
+        Diff<I1, I2>,
+        Diff<Th1, Th2>,
+        Diff<N1, N2>,
+        Diff<J1, J2>,
+        Diff<A1, A2>,
+    >;
+    fn div(self, _: Dim<L2, M2, T2, I2, Th2, N2, J2, A2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+/// The dimension of the product of two dimensions
+pub type DimProd<A, B> = <A as Mul<B>>::Output;
+/// The dimension of the quotient of two dimensions
+pub type DimQuot<A, B> = <A as Div<B>>::Output;
+
+/// Aliases for commonly used dimensions
+pub mod dims {
+    use typenum::{N1, N2, N3, P1, P2, P3, Z0};
+
+    use super::Dim;
+
+    /// A dimensionless ratio
+    pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Length, SI unit meter
+    pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Mass, SI unit kilogram
+    pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Time, SI unit second
+    pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+    /// Electric current, SI unit ampere
+    pub type Current = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+    /// Thermodynamic temperature, SI unit kelvin
+    pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+    /// Amount of substance, SI unit mole
+    pub type Amount = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+    /// Luminous intensity, SI unit candela
+    pub type Luminosity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+    /// Plane angle, SI unit radian
+    pub type Angle = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+    /// Area, m²
+    pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Volume, m³
+    pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Frequency, s⁻¹
+    pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0, Z0>;
+    /// Linear velocity, m/s
+    pub type Velocity = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0, Z0>;
+    /// Linear acceleration, m/s²
+    pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0, Z0>;
+    /// Linear jerk, m/s³
+    pub type Jerk = Dim<P1, Z0, N3, Z0, Z0, Z0, Z0, Z0>;
+    /// Angular velocity, rad/s
+    pub type AngularVelocity = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0, P1>;
+    /// Angular acceleration, rad/s²
+    pub type AngularAcceleration = Dim<Z0, Z0, N2, Z0, Z0, Z0, Z0, P1>;
+    /// Angular jerk, rad/s³
+    pub type AngularJerk = Dim<Z0, Z0, N3, Z0, Z0, Z0, Z0, P1>;
+    /// Path curvature, rad/m
+    pub type Curvature = Dim<N1, Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+    /// Force, kg·m/s²
+    pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0, Z0>;
+    /// Pressure, kg/(m·s²)
+    pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0, Z0, Z0>;
+    /// Energy, kg·m²/s²
+    pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0, Z0, Z0>;
+    /// Power, kg·m²/s³
+    pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0, Z0, Z0>;
+    /// Torque, N·m
+    ///
+    /// Torque shares the dimension of energy, the angle exponent is not used to tell them apart
+    /// so that `Newton * Meter` is a torque.
+    pub type Torque = Energy;
+    /// Moment of inertia, kg·m²
+    pub type MomentOfInertia = Dim<P2, P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+    /// Electric charge, A·s
+    pub type Charge = Dim<Z0, Z0, P1, P1, Z0, Z0, Z0, Z0>;
+    /// Voltage, kg·m²/(A·s³)
+    pub type Voltage = Dim<P2, P1, N3, N1, Z0, Z0, Z0, Z0>;
+    /// Electrical resistance, kg·m²/(A²·s³)
+    pub type Resistance = Dim<P2, P1, N3, N2, Z0, Z0, Z0, Z0>;
+}
+
+/// A value in SI base units with its dimension tracked at the type level.
+pub struct Quantity<D: Dimension> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Quantity<D> {
+    /// Creates a new quantity from a value in SI base units
+    #[must_use]
+    #[inline]
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// The value in SI base units
+    #[must_use]
+    #[inline]
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    /// The absolute value of this quantity
+    #[must_use]
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self::new(self.value.abs())
+    }
+
+    /// Converts this quantity into a named unit of the same dimension
+    #[must_use]
+    #[inline]
+    pub fn to<U: UnitQuantity<Dim = D>>(self) -> U {
+        U::from_quantity(self)
+    }
+}
+
+impl<D: Dimension> Clone for Quantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: Dimension> Copy for Quantity<D> {}
+
+impl<D: Dimension> Default for Quantity<D> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<D: Dimension> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<D: Dimension> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Quantity({self})")
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        let exponents = [
+            ("m", D::LENGTH),
+            ("kg", D::MASS),
+            ("s", D::TIME),
+            ("A", D::CURRENT),
+            ("K", D::TEMPERATURE),
+            ("mol", D::AMOUNT),
+            ("cd", D::LUMINOSITY),
+            ("rad", D::ANGLE),
+        ];
+        for (symbol, exponent) in exponents {
+            match exponent {
+                0 => {}
+                1 => write!(f, " {symbol}")?,
+                _ => write!(f, " {symbol}^{exponent}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<D: Dimension> Add for Quantity<D> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl<D: Dimension> Sub for Quantity<D> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl<D: Dimension> AddAssign for Quantity<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<D: Dimension> SubAssign for Quantity<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<D: Dimension> Neg for Quantity<D> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value)
+    }
+}
+
+impl<D1, D2> Mul<Quantity<D2>> for Quantity<D1>
+where
+    D1: Dimension + Mul<D2>,
+    D2: Dimension,
+    DimProd<D1, D2>: Dimension,
+{
+    type Output = Quantity<DimProd<D1, D2>>;
+    #[inline]
+    fn mul(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+impl<D1, D2> Div<Quantity<D2>> for Quantity<D1>
+where
+    D1: Dimension + Div<D2>,
+    D2: Dimension,
+    DimQuot<D1, D2>: Dimension,
+{
+    type Output = Quantity<DimQuot<D1, D2>>;
+    #[inline]
+    fn div(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+impl<D: Dimension> Mul<f64> for Quantity<D> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs)
+    }
+}
+
+impl<D: Dimension> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+    #[inline]
+    fn mul(self, rhs: Quantity<D>) -> Self::Output {
+        Quantity::new(self * rhs.value)
+    }
+}
+
+impl<D: Dimension> Div<f64> for Quantity<D> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs)
+    }
+}
+
+impl<D: Dimension> MulAssign<f64> for Quantity<D> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.value *= rhs;
+    }
+}
+
+impl<D: Dimension> DivAssign<f64> for Quantity<D> {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.value /= rhs;
+    }
+}
+
+impl From<Quantity<dims::Dimensionless>> for f64 {
+    #[inline]
+    fn from(value: Quantity<dims::Dimensionless>) -> Self {
+        value.value
+    }
+}
+
+impl From<f64> for Quantity<dims::Dimensionless> {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Rescales a value between two units given how many SI base units one of each is.
+///
+/// Conversions to or from the SI unit apply the scale directly,
+/// other conversions apply whichever ratio between the scales is at least one.
+/// Decimal definitions such as 0.3048 m per foot are not exact in binary,
+/// so a ratio within a few ulp of a whole number is snapped to it to keep
+/// conversions like 12 inches per foot exact.
+#[doc(hidden)]
+#[must_use]
+#[inline]
+pub fn rescale(value: f64, from_scale: f64, to_scale: f64) -> f64 {
+    #[allow(clippy::float_cmp)]
+    if to_scale == 1.0 {
+        return value * from_scale;
+    }
+    #[allow(clippy::float_cmp)]
+    if from_scale == 1.0 {
+        return value / to_scale;
+    }
+    let snap = |ratio: f64| {
+        let whole = ratio.round();
+        if (ratio - whole).abs() <= ratio * 4.0 * f64::EPSILON {
+            whole
+        } else {
+            ratio
+        }
+    };
+    if from_scale >= to_scale {
+        value * snap(from_scale / to_scale)
+    } else {
+        value / snap(to_scale / from_scale)
+    }
+}
+
+/// A named unit that is a scaled view of a [`Quantity`].
+///
+/// Implemented with [`unit_quantity!`](crate::unit_quantity).
+/// There is no blanket product between two named units, `PoundForce * Foot` only
+/// compiles if a [`unit_dim_analysis!`](crate::unit_dim_analysis) declares it.
+/// Undeclared products are taken between quantities and converted back.
+/// ```
+/// use frclib_core::units::{force::PoundForce, length::Foot, torque::FootPound};
+/// use frclib_core::units::quantity::UnitQuantity;
+///
+/// let torque: FootPound = (PoundForce::new(3.0).quantity() * Foot::new(2.0).quantity()).into();
+/// assert!((torque.value() - 6.0).abs() < 1e-12);
+/// ```
+pub trait UnitQuantity: Copy {
+    /// The dimension of the unit
+    type Dim: Dimension;
+    /// How many SI base units one of this unit is
+    const SCALE: f64;
+
+    /// Converts this unit into a dimension checked quantity
+    #[must_use]
+    fn quantity(self) -> Quantity<Self::Dim>;
+
+    /// Converts a dimension checked quantity into this unit
+    #[must_use]
+    fn from_quantity(quantity: Quantity<Self::Dim>) -> Self;
+}
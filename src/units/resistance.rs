@@ -0,0 +1,12 @@
+use crate::units::quantity::dims;
+use crate::{unit, unit_family};
+
+unit!(Ohm | Ohms: float = "Ω", "ohm");
+unit!(Milliohm | Milliohms: float = "mΩ", "mohm");
+unit!(Kiloohm | Kiloohms: float = "kΩ", "kohm");
+
+unit_family!(Resistance(Ohm) in dims::Resistance:
+    Milliohm = 0.001,
+    Kiloohm = 1000.0;
+    scale: Milliohm, Ohm, Kiloohm
+);
@@ -0,0 +1,175 @@
+//! A serde representation of units that keeps the unit they were written in.
+//!
+//! Units serialize as their bare inner number by default, so a config file has no way
+//! to say which unit a number is in. [`Tagged`] serializes a unit as `{"value": 2.0, "unit": "ft"}`
+//! and deserializes from that, from text like `"2 ft"` or from a bare number in the target unit.
+//! Any unit of the target's family is accepted and converted to the target unit.
+//!
+//! The functions in this module and in [`text`] can be used with `#[serde(with = "...")]`
+//! to tag a single field.
+//!
+//! # Example
+//! ```
+//! use frclib_core::units::length::{Foot, Meter};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "frclib_core::units::tagged")]
+//!     wheel_base: Meter,
+//!     #[serde(with = "frclib_core::units::tagged::text")]
+//!     bumper: Foot,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{ "wheel_base": { "value": 24.0, "unit": "in" }, "bumper": "30 in" }"#,
+//! ).unwrap();
+//! assert!((config.wheel_base.value() - 0.6096).abs() < 1e-12);
+//! assert_eq!(config.bumper, Foot::new(2.5));
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use num::FromPrimitive;
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{ParseFamily, Unit};
+
+/// A unit that serializes with the symbol of its unit, see the [module docs](self).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Tagged<U>(pub U);
+
+impl<U> From<U> for Tagged<U> {
+    fn from(unit: U) -> Self {
+        Self(unit)
+    }
+}
+
+impl<U: Unit + Serialize> Serialize for Tagged<U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, U: ParseFamily + FromPrimitive> Deserialize<'de> for Tagged<U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serializes a unit as `{"value": 2.0, "unit": "ft"}`
+///
+/// # Errors
+/// Returns an error if the serializer fails.
+pub fn serialize<U: Unit + Serialize, S: Serializer>(
+    unit: &U,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Tagged", 2)?;
+    state.serialize_field("value", unit)?;
+    state.serialize_field("unit", U::SYMBOL)?;
+    state.end()
+}
+
+/// Deserializes a unit from `{"value": 2.0, "unit": "ft"}`, `"2 ft"` or a bare number
+///
+/// # Errors
+/// Returns an error if the input is not one of those forms
+/// or the unit is not part of the family of `U`.
+pub fn deserialize<'de, U: ParseFamily + FromPrimitive, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<U, D::Error> {
+    deserializer.deserialize_any(TaggedVisitor(PhantomData))
+}
+
+/// Serializing units as text like `"2 ft"`, for use with `#[serde(with = "...")]`.
+pub mod text {
+    use num::{FromPrimitive, ToPrimitive};
+    use serde::{Deserializer, Serializer};
+
+    use crate::units::{ParseFamily, Unit, UnitDisplay};
+
+    /// Serializes a unit as text like `"2 ft"`
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails.
+    pub fn serialize<U: Unit + ToPrimitive, S: Serializer>(
+        unit: &U,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&UnitDisplay::new(
+            unit.to_f64().unwrap_or(f64::NAN),
+            U::SYMBOL,
+        ))
+    }
+
+    /// Deserializes a unit from `{"value": 2.0, "unit": "ft"}`, `"2 ft"` or a bare number
+    ///
+    /// # Errors
+    /// Returns an error if the input is not one of those forms
+    /// or the unit is not part of the family of `U`.
+    pub fn deserialize<'de, U: ParseFamily + FromPrimitive, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<U, D::Error> {
+        super::deserialize(deserializer)
+    }
+}
+
+struct TaggedVisitor<U>(PhantomData<U>);
+
+impl<U: FromPrimitive> TaggedVisitor<U> {
+    fn number<E: de::Error>(value: Option<U>) -> Result<U, E> {
+        value.ok_or_else(|| E::custom("number is out of range for the unit"))
+    }
+}
+
+impl<'de, U: ParseFamily + FromPrimitive> Visitor<'de> for TaggedVisitor<U> {
+    type Value = U;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a number, text like \"2 ft\" or a map with a value and unit convertible to {}",
+            U::NAME
+        )
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<U, E> {
+        Self::number(U::from_f64(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<U, E> {
+        Self::number(U::from_i64(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<U, E> {
+        Self::number(U::from_u64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<U, E> {
+        U::parse_family(text).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<U, A::Error> {
+        let mut value: Option<f64> = None;
+        let mut unit: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                "unit" if unit.is_some() => return Err(de::Error::duplicate_field("unit")),
+                "value" => value = Some(map.next_value()?),
+                "unit" => unit = Some(map.next_value()?),
+                other => return Err(de::Error::unknown_field(other, &["value", "unit"])),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        match unit {
+            Some(unit) => U::from_family(value, &unit).map_err(de::Error::custom),
+            None => Self::number(U::from_f64(value)),
+        }
+    }
+}
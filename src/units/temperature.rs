@@ -1,23 +1,24 @@
-use crate::{unit, unit_conversion, unit_family};
+use crate::units::quantity::dims;
+use crate::{unit, unit_family, unit_quantity};
 
-unit!(Celsius: float);
-unit!(Fahrenheit: float);
-unit!(Kelvin: float);
+unit!(Celsius: point(CelsiusDelta) = "°C", "C");
+unit!(Fahrenheit: point(FahrenheitDelta) = "°F", "F");
+unit!(Kelvin: point(KelvinDelta) = "K");
 
-unit_conversion!(Celsius(float) <-> Fahrenheit(float) ~ celsius_to_fahrenheit);
-unit_conversion!(Celsius(float) <-> Kelvin(float) ~ celsius_to_kelvin);
-unit_conversion!(Fahrenheit(float) <-> Kelvin(float) ~ fahrenheit_to_kelvin);
+unit!(CelsiusDelta: float = "Δ°C");
+unit!(FahrenheitDelta: float = "Δ°F");
+unit!(KelvinDelta: float = "ΔK");
 
-unit_family!(Temperature(Celsius): Fahrenheit, Kelvin);
+// temperature scales are offset from each other so they are declared as affine maps to celsius
+unit_family!(Temperature(Celsius):
+    Fahrenheit = (5.0 / 9.0, -160.0 / 9.0),
+    Kelvin = (1.0, -273.15)
+);
 
-fn celsius_to_fahrenheit(celsius: f64) -> f64 {
-    celsius.mul_add(1.8, 32.0)
-}
+// differences between temperatures have no offset and convert with the scale alone
+unit_family!(TemperatureDelta(KelvinDelta) in dims::Temperature:
+    CelsiusDelta = 1.0,
+    FahrenheitDelta = 5.0 / 9.0
+);
 
-fn celsius_to_kelvin(celsius: f64) -> f64 {
-    celsius + 273.15
-}
-
-fn fahrenheit_to_kelvin(fahrenheit: f64) -> f64 {
-    (fahrenheit + 459.67) * (5.0 / 9.0)
-}
+unit_quantity!(Kelvin: dims::Temperature = 1.0);
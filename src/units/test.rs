@@ -1,18 +1,19 @@
 use crate::units::{
     length::{Foot, Meter},
     linear_velocity::MetersPerSecond,
+    quantity::UnitQuantity,
     time::Second,
 };
 
 #[test]
 fn conversion() {
-    let meter = Meter::new(1.0);
-    let feet = Foot::new(3.28084);
+    let meter = Meter::new(0.3048);
+    let feet = Foot::new(1.0);
     assert_eq!(feet, meter);
     assert_eq!(meter, feet);
     let combined = feet + meter;
-    assert_eq!(combined, Foot::new(6.56168));
-    assert_eq!(combined, Meter::new(2.0));
+    assert_eq!(combined, Foot::new(2.0));
+    assert_eq!(combined, Meter::new(0.6096));
     assert!(combined > feet);
 }
 
@@ -23,3 +24,935 @@ fn dim_analysis() {
     let meter_per_second: MetersPerSecond = meter / second;
     assert_eq!(meter_per_second, MetersPerSecond::new(1.0));
 }
+
+#[test]
+#[cfg(feature = "value-union")]
+fn frc_value() {
+    use crate::units::{
+        length::Distance,
+        time::{Microsecond, Time},
+        FrcUnitValue, Unit,
+    };
+    use crate::value::{CastErrorReason, FrcType, FrcValue, FrcValueCastError, StaticallyFrcTyped};
+
+    assert_eq!(FrcValue::from(Meter::new(2.0)), FrcValue::Double(2.0));
+    assert_eq!(Meter::TYPE, FrcType::Double);
+    assert_eq!(
+        Meter::try_from(FrcValue::Double(2.0)).ok(),
+        Some(Meter::new(2.0))
+    );
+    assert_eq!(
+        Meter::try_from(FrcValue::Float(2.0)).ok(),
+        Some(Meter::new(2.0))
+    );
+    assert!(matches!(
+        Meter::try_from(FrcValue::Boolean(true)),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::Boolean,
+            "Meter",
+            CastErrorReason::Type
+        ))
+    ));
+
+    assert_eq!(FrcValue::from(Microsecond::new(5)), FrcValue::Int(5));
+    assert_eq!(
+        FrcValue::from(Microsecond::new(i64::MAX.unsigned_abs())),
+        FrcValue::Int(i64::MAX)
+    );
+    assert_eq!(
+        FrcValue::from(Microsecond::new(i64::MAX.unsigned_abs() + 1)),
+        FrcValue::Int(i64::MAX)
+    );
+    assert_eq!(
+        FrcValue::from(Microsecond::new(u64::MAX)),
+        FrcValue::Int(i64::MAX)
+    );
+    assert_eq!(Microsecond::TYPE, FrcType::Int);
+    assert_eq!(
+        Microsecond::try_from(FrcValue::Int(5)).ok(),
+        Some(Microsecond::new(5))
+    );
+
+    assert_eq!(Foot::NAME, "Foot");
+    let feet = FrcUnitValue::new(Foot::new(2.0));
+    assert_eq!(feet.value, FrcValue::Double(2.0));
+    let meters = Meter::from_unit_value(feet).expect("Foot is a distance");
+    assert_eq!(meters, Meter::new(0.6096));
+    assert_eq!(
+        Meter::from_frc_value(FrcValue::Double(1.0), "Meter").ok(),
+        Some(Meter::new(1.0))
+    );
+    assert!(matches!(
+        Meter::from_frc_value(FrcValue::Double(1.0), "Second"),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::Double,
+            "Distance",
+            CastErrorReason::UnknownUnit
+        ))
+    ));
+    let seconds = Second::from_unit_value(FrcUnitValue::from_parts(
+        FrcValue::Int(1_500),
+        "Microsecond",
+    ))
+    .expect("Microsecond is a time");
+    assert_eq!(seconds, Second::new(0.0015));
+}
+
+#[test]
+#[allow(deprecated)]
+fn moved_electrical_units() {
+    use crate::units::{current, energy, power, resistance, voltage};
+
+    assert_eq!(energy::Volt::new(1.0), voltage::Volt::new(1.0));
+    assert_eq!(energy::Amp::new(1.0), current::Amp::new(1.0));
+    assert_eq!(energy::Watt::new(1.0), power::Watt::new(1.0));
+    assert_eq!(energy::Ohm::new(1.0), resistance::Ohm::new(1.0));
+}
+
+#[test]
+fn quantity() {
+    use crate::units::{
+        current::Amp,
+        mass::Kilogram,
+        power::Watt,
+        quantity::{dims, Quantity},
+        resistance::Ohm,
+        torque::{FootPound, NewtonMeter},
+        voltage::Volt,
+    };
+
+    assert_eq!(Volt::new(12.0) * Amp::new(2.0), Watt::new(24.0));
+    assert_eq!(Amp::new(2.0) * Ohm::new(6.0), Volt::new(12.0));
+
+    let force: Quantity<dims::Force> = Kilogram::new(2.0).quantity()
+        * (MetersPerSecond::new(3.0).quantity() / Second::new(1.5).quantity());
+    let torque: NewtonMeter = (force * Foot::new(1.0).quantity()).into();
+    assert!((torque.value() - 1.2192).abs() < 1e-12);
+    let foot_pounds: FootPound = torque.quantity().to();
+    assert!((foot_pounds.quantity().value() - torque.value()).abs() < 1e-12);
+
+    let ratio: f64 = (Meter::new(3.0).quantity() / Foot::new(1.0).quantity()).into();
+    assert!((ratio - 3.0 / 0.3048).abs() < 1e-12);
+    assert_eq!(format!("{force}"), "4 m kg s^-2");
+}
+
+#[test]
+fn electrical() {
+    use crate::units::{
+        charge::{AmpHour, Charge, Coulomb, MilliampHour},
+        current::{Amp, Current, Milliamp},
+        energy::{Energy, Joule, WattHour},
+        power::{Kilowatt, Watt},
+        resistance::{Milliohm, Ohm},
+        time::Hour,
+        voltage::{Millivolt, Volt, Voltage},
+    };
+
+    fn sag(current: impl Current, resistance: Ohm) -> Volt {
+        current.standard() * resistance
+    }
+
+    assert_eq!(
+        sag(Milliamp::new(200_000.0), Milliohm::new(15.0).into()),
+        Volt::new(3.0)
+    );
+    assert_eq!(
+        Volt::new(12.0).conv::<Millivolt>(),
+        Millivolt::new(12_000.0)
+    );
+    assert_eq!(Watt::new(600.0) / Volt::new(12.0), Amp::new(50.0));
+    assert_eq!(Kilowatt::from(Watt::new(1500.0)), Kilowatt::new(1.5));
+
+    let capacity = Amp::new(18.0) * Hour::new(1.0);
+    assert_eq!(capacity, AmpHour::new(18.0));
+    assert_eq!(capacity.conv::<MilliampHour>(), MilliampHour::new(18_000.0));
+    assert_eq!(capacity.standard(), Coulomb::new(64_800.0));
+
+    let energy = Volt::new(12.0) * Coulomb::new(10.0);
+    assert_eq!(energy, Joule::new(120.0));
+    assert_eq!(energy.conv::<WattHour>(), WattHour::new(120.0 / 3600.0));
+    assert_eq!(Watt::new(3600.0) * Second::new(1.0), Joule::new(3600.0));
+}
+
+#[test]
+fn mechanical() {
+    use crate::units::{
+        angle::Rotation,
+        angular_velocity::RotationPerMin,
+        area::{SquareInch, SquareMeter},
+        force::{Force, Newton, PoundForce},
+        frequency::{Hertz, RevolutionsPerMinute},
+        linear_acceleration::MetersPerSecSqr,
+        mass::Kilogram,
+        pressure::{Pascal, Psi},
+        torque::NewtonMeter,
+        volume::{CubicInch, CubicMeter, Liter},
+    };
+
+    let weight = Kilogram::new(2.0) * MetersPerSecSqr::new(9.806_65);
+    assert_eq!(weight, Newton::new(19.6133));
+    assert!((weight.conv::<PoundForce>().value() - 2.0 / 0.453_592_37).abs() < 1e-12);
+    assert_eq!(Newton::new(10.0) * Meter::new(0.5), NewtonMeter::new(5.0));
+    assert_eq!(
+        Pascal::new(100.0) * SquareMeter::new(2.0),
+        Newton::new(200.0)
+    );
+
+    let piston = Psi::new(60.0) * SquareInch::new(1.5);
+    assert_eq!(piston, PoundForce::new(90.0));
+    assert!((Newton::from(piston).value() - 400.339_945_373_445).abs() < 1e-9);
+
+    assert_eq!(
+        SquareMeter::new(2.0) * Meter::new(3.0),
+        CubicMeter::new(6.0)
+    );
+    assert_eq!(Liter::from(CubicMeter::new(1.0)), Liter::new(1000.0));
+    assert!((CubicInch::from(Liter::new(1.0)).value() - 61.023_744_094_732_28).abs() < 1e-9);
+
+    assert_eq!(
+        Hertz::from(RevolutionsPerMinute::new(120.0)),
+        Hertz::new(2.0)
+    );
+    assert_eq!(
+        RevolutionsPerMinute::new(60.0) * Rotation::new(2.0),
+        RotationPerMin::new(120.0)
+    );
+}
+
+#[test]
+fn motion_profile() {
+    use crate::units::{
+        angle::Radian,
+        angular_acceleration::RadianPerSecSqr,
+        angular_jerk::{DegreePerSecCubed, RadianPerSecCubed},
+        angular_velocity::RadianPerSec,
+        curvature::{Curvature, RadianPerFoot, RadianPerMeter},
+        linear_acceleration::{FeetPerSecSqr, MetersPerSecSqr},
+        linear_jerk::{FeetPerSecCubed, MetersPerSecCubed},
+        linear_velocity::FeetPerSecond,
+    };
+
+    let accel = MetersPerSecCubed::new(4.0) * Second::new(0.5);
+    assert_eq!(accel, MetersPerSecSqr::new(2.0));
+    assert_eq!(accel * Second::new(1.5), MetersPerSecond::new(3.0));
+    assert_eq!(
+        FeetPerSecCubed::new(2.0) * Second::new(2.0),
+        FeetPerSecSqr::new(4.0)
+    );
+    assert_eq!(
+        MetersPerSecSqr::new(2.0) / Second::new(0.5),
+        MetersPerSecCubed::new(4.0)
+    );
+    assert!(
+        (RadianPerSecCubed::from(DegreePerSecCubed::new(180.0)).value() - std::f64::consts::PI)
+            .abs()
+            < 1e-12
+    );
+    assert_eq!(
+        RadianPerSecCubed::new(1.0) * Second::new(2.0),
+        RadianPerSecSqr::new(2.0)
+    );
+
+    let curvature = RadianPerMeter::new(0.5);
+    assert_eq!(
+        curvature * MetersPerSecond::new(2.0),
+        RadianPerSec::new(1.0)
+    );
+    assert_eq!(curvature * Meter::new(4.0), Radian::new(2.0));
+    assert_eq!(
+        RadianPerSec::new(1.0) / MetersPerSecond::new(2.0),
+        curvature
+    );
+    assert_eq!(
+        RadianPerFoot::new(1.0) * FeetPerSecond::new(2.0),
+        RadianPerSec::new(2.0)
+    );
+    assert!((RadianPerFoot::new(1.0).standard().value() - 1.0 / 0.3048).abs() < 1e-12);
+}
+
+#[test]
+fn angle_math() {
+    use crate::units::angle::{unwrap_angles, AngleExt, Degree, Radian, Rotation};
+    use std::f64::consts::PI;
+
+    assert_eq!((Degree(350.0) + Degree(20.0)).wrap(), Degree(10.0));
+    assert_eq!(Degree(180.0).wrap(), Degree(-180.0));
+    assert_eq!(Degree(-190.0).wrap(), Degree(170.0));
+    assert_eq!(Degree(-30.0).wrap_positive(), Degree(330.0));
+    assert_eq!(Degree(720.0).wrap_positive(), Degree(0.0));
+    assert_eq!(Rotation(2.75).wrap(), Rotation(-0.25));
+    assert_eq!(Radian(3.0 * PI).wrap(), Radian(-PI));
+    assert!((Radian(-0.5).wrap_positive().0 - (std::f64::consts::TAU - 0.5)).abs() < 1e-12);
+
+    assert_eq!(
+        Degree(350.0).shortest_difference(Degree(10.0)),
+        Degree(20.0)
+    );
+    assert_eq!(
+        Degree(10.0).shortest_difference(Degree(350.0)),
+        Degree(-20.0)
+    );
+    assert_eq!(
+        Degree(90.0).shortest_difference(Rotation(-0.25)),
+        Degree(-180.0)
+    );
+    assert_eq!(Degree(5.0).unwrap_near(Degree(710.0)), Degree(725.0));
+    assert_eq!(
+        unwrap_angles([Degree(170.0), Degree(-170.0), Degree(-10.0), Degree(160.0)]),
+        vec![Degree(170.0), Degree(190.0), Degree(350.0), Degree(520.0)]
+    );
+
+    assert!((Degree(30.0).sin() - 0.5).abs() < 1e-12);
+    assert!((Rotation(0.5).cos() + 1.0).abs() < 1e-12);
+    assert!((Degree(45.0).tan() - 1.0).abs() < 1e-12);
+    assert_eq!(
+        <Degree as AngleExt>::atan2(Meter(1.0), Meter(-1.0)),
+        Degree(135.0)
+    );
+    assert_eq!(Radian::atan2(0.0, -1.0), Radian(PI));
+}
+
+#[test]
+fn linear_algebra() {
+    use nalgebra::{Vector2, Vector3};
+
+    let displacement = Vector3::new(Meter::new(3.0), Meter::new(4.0), Meter::new(12.0));
+    assert_eq!(displacement.norm(), Meter::new(13.0));
+    assert_eq!(displacement.abs().max(), Meter::new(12.0));
+
+    let offset = Vector3::new(Meter::new(1.0), Meter::new(0.0), Meter::new(-2.0));
+    assert_eq!(
+        displacement - offset,
+        Vector3::new(Meter::new(2.0), Meter::new(4.0), Meter::new(14.0))
+    );
+    assert_eq!(
+        displacement + offset,
+        Vector3::new(Meter::new(4.0), Meter::new(4.0), Meter::new(10.0))
+    );
+
+    let velocity = Vector2::new(MetersPerSecond::new(6.0), MetersPerSecond::new(8.0));
+    assert_eq!(velocity.norm(), MetersPerSecond::new(10.0));
+    assert_eq!(
+        velocity.map(|component| component * 0.5),
+        Vector2::new(MetersPerSecond::new(3.0), MetersPerSecond::new(4.0))
+    );
+    assert_eq!(
+        -velocity,
+        Vector2::new(MetersPerSecond::new(-6.0), MetersPerSecond::new(-8.0))
+    );
+
+    use crate::units::angle::{Degree, Radian};
+    use simba::scalar::ComplexField;
+
+    let quarter = Radian::from(Degree::new(90.0));
+    assert_close(ComplexField::sin(quarter).value(), 1.0);
+    assert_close(ComplexField::tan(quarter / 2.0).value(), 1.0);
+}
+
+/// Asserts two values agree to within a few rounding steps of each other
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= expected.abs() * 4.0 * f64::EPSILON,
+        "{actual} != {expected}"
+    );
+}
+
+/// A deterministic spread of values across many magnitudes and both signs
+fn sample_values() -> impl Iterator<Item = f64> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..256)
+        .map(move |_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let mantissa = f64::from(u32::try_from(state >> 32).unwrap_or(0)) / f64::from(u32::MAX);
+            let exponent = i32::try_from(state % 25).unwrap_or(0) - 12;
+            let sign = if state & 0x100 == 0 { 1.0 } else { -1.0 };
+            sign * mantissa * 10f64.powi(exponent)
+        })
+        .chain([0.0, 1.0, -1.0, 12.0, 1e9])
+}
+
+/// Checks a conversion round-trips and agrees with converting through SI base units
+fn check_si_conversion<A, B>()
+where
+    A: UnitQuantity + From<f64> + From<B>,
+    B: UnitQuantity<Dim = A::Dim> + From<A>,
+{
+    for value in sample_values() {
+        let a = A::from(value);
+        let si = a.quantity().value();
+        let b = B::from(a);
+        assert_close(b.quantity().value(), si);
+        assert_close(B::from_quantity(a.quantity()).quantity().value(), si);
+        assert_close(A::from(b).quantity().value(), si);
+    }
+}
+
+/// Checks a conversion between units without SI quantities, such as the offset
+/// temperature scales, round-trips in both directions
+fn check_round_trip<A, B>()
+where
+    A: Copy + From<f64> + Into<f64> + From<B>,
+    B: Copy + From<f64> + Into<f64> + From<A>,
+{
+    for value in sample_values() {
+        let there: f64 = B::from(A::from(value)).into();
+        let back: f64 = A::from(B::from(there)).into();
+        let tolerance = value.abs().max(there.abs()) * 4.0 * f64::EPSILON;
+        assert!((back - value).abs() <= tolerance, "{back} != {value}");
+    }
+}
+
+/// Checks whole microsecond counts convert to `A` and back without drifting
+fn check_microsecond_conversion<A>(micros_per_unit: f64)
+where
+    A: Copy + Into<f64> + From<crate::units::time::Microsecond>,
+    crate::units::time::Microsecond: From<A>,
+{
+    use crate::units::time::Microsecond;
+    for micros in [0_u32, 1, 999, 1500, 1_000_000, 86_400_000, u32::MAX] {
+        let count = Microsecond::new(u64::from(micros));
+        let converted = A::from(count);
+        assert_close(converted.into(), f64::from(micros) / micros_per_unit);
+        assert_eq!(Microsecond::from(converted), count);
+    }
+}
+
+macro_rules! check_si_conversions {
+    ($($module:ident: $($a:ident <-> $b:ident),+;)+) => {
+        $($(
+            check_si_conversion::<crate::units::$module::$a, crate::units::$module::$b>();
+            check_si_conversion::<crate::units::$module::$b, crate::units::$module::$a>();
+        )+)+
+    };
+}
+
+#[test]
+fn conversion_accuracy() {
+    check_si_conversions!(
+        angle: Degree <-> Radian, Degree <-> Rotation, Radian <-> Rotation;
+        angular_acceleration: DegreePerSecSqr <-> RadianPerSecSqr, DegreePerSecSqr <-> RotationPerSecSqr, DegreePerSecSqr <-> RotationPerMinSqr, RadianPerSecSqr <-> RotationPerSecSqr, RadianPerSecSqr <-> RotationPerMinSqr, RotationPerSecSqr <-> RotationPerMinSqr;
+        angular_jerk: DegreePerSecCubed <-> RadianPerSecCubed, DegreePerSecCubed <-> RotationPerSecCubed, RadianPerSecCubed <-> RotationPerSecCubed;
+        angular_velocity: DegreePerSec <-> RadianPerSec, DegreePerSec <-> RotationPerSec, DegreePerSec <-> RotationPerMin, RadianPerSec <-> RotationPerSec, RadianPerSec <-> RotationPerMin, RotationPerSec <-> RotationPerMin;
+        area: SquareMeter <-> SquareCentimeter, SquareMeter <-> SquareFoot, SquareMeter <-> SquareInch, SquareCentimeter <-> SquareFoot, SquareCentimeter <-> SquareInch, SquareFoot <-> SquareInch;
+        charge: Coulomb <-> AmpHour, Coulomb <-> MilliampHour, AmpHour <-> MilliampHour;
+        current: Amp <-> Milliamp;
+        curvature: RadianPerMeter <-> DegreePerMeter, RadianPerMeter <-> RadianPerFoot, DegreePerMeter <-> RadianPerFoot;
+        energy: Joule <-> Kilojoule, Joule <-> WattHour, Joule <-> KilowattHour, Kilojoule <-> WattHour, Kilojoule <-> KilowattHour, WattHour <-> KilowattHour;
+        force: Newton <-> Kilonewton, Newton <-> PoundForce, Newton <-> KilogramForce, Kilonewton <-> PoundForce, Kilonewton <-> KilogramForce, PoundForce <-> KilogramForce;
+        frequency: Hertz <-> Kilohertz, Hertz <-> RevolutionsPerMinute, Kilohertz <-> RevolutionsPerMinute;
+        length: Meter <-> Foot, Meter <-> Inch, Foot <-> Inch, Meter <-> Centimeter, Centimeter <-> Foot, Centimeter <-> Inch;
+        linear_acceleration: MetersPerSecSqr <-> KilometersPerHrSqr, MetersPerSecSqr <-> MilesPerHrSqr, MetersPerSecSqr <-> FeetPerSecSqr, FeetPerSecSqr <-> MilesPerHrSqr, FeetPerSecSqr <-> KilometersPerHrSqr, MilesPerHrSqr <-> KilometersPerHrSqr;
+        linear_jerk: MetersPerSecCubed <-> FeetPerSecCubed;
+        linear_velocity: MetersPerSecond <-> KilometersPerHour, MetersPerSecond <-> MilesPerHour, MetersPerSecond <-> FeetPerSecond, FeetPerSecond <-> MilesPerHour, FeetPerSecond <-> KilometersPerHour, MilesPerHour <-> KilometersPerHour;
+        mass: Kilogram <-> Gram, Kilogram <-> Pound, Kilogram <-> Ounce, Gram <-> Pound, Gram <-> Ounce, Pound <-> Ounce;
+        moment_of_inertia: KilogramSquareMeter <-> PoundSquareFoot;
+        power: Watt <-> Milliwatt, Watt <-> Kilowatt, Milliwatt <-> Kilowatt;
+        pressure: Pascal <-> Kilopascal, Pascal <-> Psi, Pascal <-> Bar, Pascal <-> Atmosphere, Kilopascal <-> Psi, Kilopascal <-> Bar, Kilopascal <-> Atmosphere, Psi <-> Bar, Psi <-> Atmosphere, Bar <-> Atmosphere;
+        resistance: Ohm <-> Milliohm, Ohm <-> Kiloohm, Milliohm <-> Kiloohm;
+        time: Second <-> Millisecond, Hour <-> Second, Minute <-> Second, Hour <-> Minute, Minute <-> Millisecond, Hour <-> Millisecond;
+        torque: NewtonMeter <-> NewtonCentimeter, NewtonMeter <-> KilogramMeter, NewtonMeter <-> FootPound, NewtonMeter <-> InchPound, NewtonCentimeter <-> KilogramMeter, NewtonCentimeter <-> FootPound, NewtonCentimeter <-> InchPound, KilogramMeter <-> FootPound, KilogramMeter <-> InchPound, FootPound <-> InchPound;
+        voltage: Volt <-> Millivolt;
+        volume: CubicMeter <-> Liter, CubicMeter <-> Milliliter, CubicMeter <-> CubicInch, CubicMeter <-> CubicFoot, Liter <-> Milliliter, Liter <-> CubicInch, Liter <-> CubicFoot, Milliliter <-> CubicInch, Milliliter <-> CubicFoot, CubicInch <-> CubicFoot;
+        temperature: KelvinDelta <-> CelsiusDelta, KelvinDelta <-> FahrenheitDelta, CelsiusDelta <-> FahrenheitDelta;
+    );
+
+    {
+        use crate::units::data::{
+            Bit, Byte, Gibibyte, Gigabit, Gigabyte, Kibibyte, Kilobit, Kilobyte, Mebibyte, Megabit,
+            Megabyte, Tebibyte, Terabyte,
+        };
+        use crate::units::data_rate::{
+            BitsPerSecond, BytesPerSecond, GigabitsPerSecond, GigabytesPerHour, KibibytesPerSecond,
+            KilobitsPerSecond, KilobytesPerSecond, MebibytesPerSecond, MegabitsPerSecond,
+            MegabytesPerSecond,
+        };
+        check_round_trip::<Byte, Kilobyte>();
+        check_round_trip::<Byte, Megabyte>();
+        check_round_trip::<Byte, Gigabyte>();
+        check_round_trip::<Byte, Terabyte>();
+        check_round_trip::<Byte, Kibibyte>();
+        check_round_trip::<Byte, Mebibyte>();
+        check_round_trip::<Byte, Gibibyte>();
+        check_round_trip::<Byte, Tebibyte>();
+        check_round_trip::<Byte, Bit>();
+        check_round_trip::<Byte, Kilobit>();
+        check_round_trip::<Byte, Megabit>();
+        check_round_trip::<Byte, Gigabit>();
+        check_round_trip::<Kibibyte, Kilobyte>();
+        check_round_trip::<Megabit, Mebibyte>();
+        check_round_trip::<BytesPerSecond, KilobytesPerSecond>();
+        check_round_trip::<BytesPerSecond, MegabytesPerSecond>();
+        check_round_trip::<BytesPerSecond, GigabytesPerHour>();
+        check_round_trip::<BytesPerSecond, KibibytesPerSecond>();
+        check_round_trip::<BytesPerSecond, MebibytesPerSecond>();
+        check_round_trip::<BytesPerSecond, BitsPerSecond>();
+        check_round_trip::<BytesPerSecond, KilobitsPerSecond>();
+        check_round_trip::<BytesPerSecond, MegabitsPerSecond>();
+        check_round_trip::<BytesPerSecond, GigabitsPerSecond>();
+        check_round_trip::<MegabitsPerSecond, MebibytesPerSecond>();
+    }
+
+    {
+        use crate::units::temperature::{Celsius, Fahrenheit, Kelvin};
+        check_round_trip::<Celsius, Fahrenheit>();
+        check_round_trip::<Celsius, Kelvin>();
+        check_round_trip::<Fahrenheit, Kelvin>();
+    }
+
+    {
+        use crate::units::time::{Hour, Millisecond, Minute};
+        check_microsecond_conversion::<Second>(1_000_000.0);
+        check_microsecond_conversion::<Millisecond>(1000.0);
+        check_microsecond_conversion::<Minute>(60_000_000.0);
+        check_microsecond_conversion::<Hour>(3_600_000_000.0);
+    }
+}
+
+#[test]
+fn exact_conversions() {
+    use crate::units::{
+        length::Inch,
+        mass::{Kilogram, Ounce, Pound},
+        temperature::{Celsius, Fahrenheit, Kelvin},
+        time::Microsecond,
+        torque::{FootPound, InchPound},
+    };
+
+    assert_eq!(Meter::from(Foot::new(1.0)), Meter::new(0.3048));
+    assert_eq!(Foot::from(Inch::new(12.0)), Foot::new(1.0));
+    assert_eq!(Kilogram::from(Pound::new(1.0)), Kilogram::new(0.453_592_37));
+    assert_eq!(Ounce::from(Pound::new(1.0)), Ounce::new(16.0));
+    assert_eq!(InchPound::from(FootPound::new(1.0)), InchPound::new(12.0));
+    for value in sample_values() {
+        let foot = Foot::new(value);
+        assert_close(Foot::from(Meter::from(foot)).value(), value);
+    }
+
+    assert_eq!(Second::from(Microsecond::new(1500)), Second::new(0.0015));
+    assert_eq!(
+        Microsecond::from(Second::new(0.0015)),
+        Microsecond::new(1500)
+    );
+
+    assert_close(Fahrenheit::from(Celsius::new(100.0)).value(), 212.0);
+    assert_close(Celsius::from(Fahrenheit::new(212.0)).value(), 100.0);
+    assert_close(Kelvin::from(Fahrenheit::new(32.0)).value(), 273.15);
+    for value in sample_values().filter(|value| value.abs() < 1e6) {
+        let celsius = Celsius::new(value);
+        let through_kelvin = Fahrenheit::from(Kelvin::from(celsius));
+        let fahrenheit = Fahrenheit::from(celsius);
+        assert!((fahrenheit.value() - through_kelvin.value()).abs() < 1e-9);
+        assert!((Celsius::from(fahrenheit).value() - value).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn text() {
+    use crate::units::{
+        angle::{Angle, Degree, Radian},
+        data::{Byte, Data},
+        length::{Distance, Inch},
+        time::{Microsecond, Time},
+        UnitParseError,
+    };
+
+    assert_eq!(Meter::parse("12in"), Ok(Meter::from(Inch::new(12.0))));
+    assert_eq!(Foot::parse("-3.5e1 ft"), Ok(Foot::new(-35.0)));
+    assert_eq!(Meter::parse("2"), Ok(Meter::new(2.0)));
+    assert_eq!("3.5 feet".parse::<Foot>(), Ok(Foot::new(3.5)));
+    assert_eq!("90°".parse::<Degree>(), Ok(Degree::new(90.0)));
+    assert_eq!(Degree::parse("2.5 rad"), Ok(Degree::from(Radian::new(2.5))));
+    assert_eq!(Second::parse("1500 us"), Ok(Second::new(0.0015)));
+    assert_eq!("1500µs".parse::<Microsecond>(), Ok(Microsecond::new(1500)));
+
+    assert_eq!(
+        "3 ft".parse::<Meter>(),
+        Err(UnitParseError::UnknownSuffix {
+            suffix: "ft".to_owned(),
+            expected: "Meter",
+        })
+    );
+    assert_eq!(
+        Meter::parse("3 kg"),
+        Err(UnitParseError::UnknownSuffix {
+            suffix: "kg".to_owned(),
+            expected: "Distance",
+        })
+    );
+    assert_eq!(
+        Meter::parse("ft"),
+        Err(UnitParseError::InvalidNumber("ft".to_owned()))
+    );
+
+    assert_eq!(format!("{:.1}", Foot::new(3.25).display()), "3.2 ft");
+    assert_eq!(Meter::new(2.0).display().to_string(), "2 m");
+    assert_eq!(
+        format!("{:.1}", Byte::new(1_500_000.0).display_scaled()),
+        "1.5 MB"
+    );
+    assert_eq!(Byte::new(512.0).display_scaled().to_string(), "512 B");
+    assert_eq!(Byte::new(0.5).display_scaled().to_string(), "0.5 B");
+    assert_eq!(Second::new(90.0).display_scaled().to_string(), "1.5 min");
+}
+
+#[test]
+fn conversion_graph() {
+    use crate::units::{
+        data::{Data, Gigabyte, Kilobyte},
+        length::{Distance, Inch},
+        temperature::{Celsius, Fahrenheit, Kelvin, Temperature},
+        torque::{FootPound, InchPound, Torque},
+        FamilyUnit, UnitMap,
+    };
+    use crate::{unit, unit_member};
+
+    assert_eq!(InchPound::from(FootPound::new(1.0)), InchPound::new(12.0));
+    assert_close(FootPound::new(1.0).to_inch_pound().value(), 12.0);
+    assert_close(Kilobyte::new(2.0).to_gigabyte().value(), 0.000_002);
+    assert_eq!(Gigabyte::new(1.0), Kilobyte::new(1_000_000.0));
+    assert_eq!(
+        FootPound::unit_map(),
+        UnitMap::linear(0.3048 * 0.453_592_37 * 9.806_65)
+    );
+
+    assert_close(Fahrenheit::new(32.0).to_kelvin().value(), 273.15);
+    assert_close(Kelvin::new(0.0).conv::<Fahrenheit>().value(), -459.67);
+    assert_close(Celsius::new(-40.0).to_fahrenheit().value(), -40.0);
+    assert_eq!(Kelvin::unit_map(), UnitMap::affine(1.0, -273.15));
+
+    unit!(Furlong: float = "fur");
+    unit_member!(Meter: Furlong = 201.168; Foot, Inch);
+
+    assert_eq!(Foot::from(Furlong::new(1.0)), Foot::new(660.0));
+    assert_eq!(Inch::from(Furlong::new(1.0)), Inch::new(7920.0));
+    assert_close(Furlong::new(1.0).to_feet().value(), 660.0);
+    assert_eq!(
+        Meter::parse("1 fur"),
+        Err(crate::units::UnitParseError::UnknownSuffix {
+            suffix: "fur".to_owned(),
+            expected: "Distance",
+        })
+    );
+    assert_eq!("2 fur".parse::<Furlong>(), Ok(Furlong::new(2.0)));
+}
+
+#[test]
+fn member_tagged_round_trip() {
+    use crate::units::tagged::Tagged;
+    use crate::{unit, unit_member};
+
+    unit!(Furlong: float = "fur");
+    unit_member!(Meter: Furlong = 201.168);
+
+    let json = serde_json::to_string(&Tagged(Furlong::new(2.0))).expect("furlongs serialize");
+    assert_eq!(json, r#"{"value":2.0,"unit":"fur"}"#);
+    assert_eq!(
+        serde_json::from_str::<Tagged<Furlong>>(&json).expect("furlongs round-trip"),
+        Tagged(Furlong::new(2.0))
+    );
+    assert_eq!(
+        serde_json::from_str::<Tagged<Furlong>>(r#""1.5 fur""#).expect("text in furlongs"),
+        Tagged(Furlong::new(1.5))
+    );
+    let from_feet = serde_json::from_str::<Tagged<Furlong>>(r#"{ "value": 330.0, "unit": "ft" }"#)
+        .expect("any unit of the family");
+    assert_close(from_feet.0.value(), 0.5);
+}
+
+#[test]
+fn temperature_points() {
+    use crate::units::temperature::{
+        Celsius, CelsiusDelta, Fahrenheit, FahrenheitDelta, Kelvin, KelvinDelta, Temperature,
+        TemperatureDelta,
+    };
+
+    let ambient = Celsius::new(20.0);
+    let warmed = ambient + CelsiusDelta::new(5.0);
+    assert_eq!(warmed, Celsius::new(25.0));
+    assert_close(warmed.to_fahrenheit().value(), 77.0);
+    assert_close(CelsiusDelta::new(5.0).to_fahrenheit_delta().value(), 9.0);
+
+    let rise = Fahrenheit::new(212.0) - Fahrenheit::new(32.0);
+    assert_eq!(rise, FahrenheitDelta::new(180.0));
+    assert_close(CelsiusDelta::from(rise).value(), 100.0);
+    assert_close((ambient + rise).value(), 120.0);
+    assert_close(
+        (Kelvin::new(300.0) - CelsiusDelta::new(10.0)).value(),
+        290.0,
+    );
+
+    let mut motor = Celsius::new(40.0);
+    motor += FahrenheitDelta::new(18.0);
+    motor -= KelvinDelta::new(5.0);
+    assert_close(motor.value(), 45.0);
+    assert_close((CelsiusDelta::new(2.0) + motor).value(), 47.0);
+    assert_close((motor - ambient).standard().value(), 25.0);
+}
+
+#[test]
+fn tagged_serde() {
+    use crate::units::{
+        length::Inch,
+        tagged::{self, Tagged},
+        temperature::Celsius,
+        time::Microsecond,
+    };
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Config {
+        #[serde(with = "tagged")]
+        wheel_base: Meter,
+        #[serde(with = "tagged::text")]
+        bumper: Foot,
+        limit: Tagged<Celsius>,
+        timeout: Tagged<Microsecond>,
+        plain: Meter,
+    }
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "wheel_base": { "value": 24.0, "unit": "in" },
+            "bumper": "30 in",
+            "limit": "212 °F",
+            "timeout": { "unit": "ms", "value": 1.5 },
+            "plain": 2.0
+        }"#,
+    )
+    .expect("config is valid");
+    assert_eq!(config.wheel_base, Meter::from(Inch::new(24.0)));
+    assert_eq!(config.bumper, Foot::new(2.5));
+    assert_close(config.limit.0.value(), 100.0);
+    assert_eq!(config.timeout, Tagged(Microsecond::new(1500)));
+    assert_eq!(config.plain, Meter::new(2.0));
+
+    let json = serde_json::to_value(&config).expect("config serializes");
+    assert_eq!(json["wheel_base"]["unit"], "m");
+    assert_eq!(json["bumper"], "2.5 ft");
+    assert_eq!(
+        json["timeout"],
+        serde_json::json!({ "value": 1500, "unit": "µs" })
+    );
+    assert_eq!(
+        serde_json::from_value::<Config>(json).expect("config round trips"),
+        config
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Tagged<Foot>>("3").expect("bare numbers are in feet"),
+        Tagged(Foot::new(3.0))
+    );
+    assert!(serde_json::from_str::<Tagged<Foot>>(r#""3 kg""#).is_err());
+    assert!(serde_json::from_str::<Tagged<Foot>>(r#"{ "unit": "ft" }"#).is_err());
+    assert!(serde_json::from_str::<Tagged<Foot>>(r#"{ "value": 3, "units": "ft" }"#).is_err());
+}
+
+#[test]
+fn data_units() {
+    use crate::units::{
+        data::{Byte, Data, Gigabit, Kibibyte, Mebibyte, Megabit},
+        data_rate::{DataRate, MebibytesPerSecond, MegabitsPerSecond},
+        time::Second,
+    };
+
+    assert_eq!(Byte::from(Megabit::new(8.0)), Byte::new(1_000_000.0));
+    assert_eq!(Kibibyte::from(Mebibyte::new(2.0)), Kibibyte::new(2048.0));
+    assert_close(Gigabit::new(1.0).to_byte().value(), 125_000_000.0);
+
+    assert_eq!(
+        MegabitsPerSecond::new(4.0) * Second::new(2.0),
+        Megabit::new(8.0)
+    );
+    assert_eq!(
+        Mebibyte::new(6.0) / Second::new(3.0),
+        MebibytesPerSecond::new(2.0)
+    );
+
+    assert_eq!(
+        Mebibyte::new(1536.0).display_scaled().to_string(),
+        "1.5 GiB"
+    );
+    assert_eq!(
+        Megabit::new(2500.0).display_scaled().to_string(),
+        "2.5 Gbit"
+    );
+    assert_eq!(
+        Byte::new(1_500_000.0).display_scaled().to_string(),
+        "1.5 MB"
+    );
+    assert_eq!(
+        MegabitsPerSecond::new(0.5).display_scaled().to_string(),
+        "500 kbit/s"
+    );
+
+    let limit = MegabitsPerSecond::parse("4 Mbps").expect("Mbps is a data rate");
+    assert_eq!(limit, MegabitsPerSecond::new(4.0));
+    assert_close(
+        MegabitsPerSecond::parse("1 MB/s")
+            .expect("MB/s is a data rate")
+            .value(),
+        8.0,
+    );
+}
+
+#[test]
+fn frc_mechanisms() {
+    use crate::units::{
+        angle::{Degree, Rotation},
+        angular_velocity::{RotationPerMin, RotationPerSec},
+        constants::{FULL_ROTATION, STANDARD_GRAVITY},
+        frc::{Encoder, GearRatio, Wheel},
+        length::Inch,
+        mechanism::Mechanism,
+        time::{Millisecond, Second},
+        torque::NewtonMeter,
+    };
+
+    assert_close(Rotation::from(FULL_ROTATION).value(), 1.0);
+    assert_close((STANDARD_GRAVITY * Second::new(2.0)).value(), 19.6133);
+
+    let wheel = Wheel::new(Inch::new(4.0));
+    assert_close(wheel.circumference().value(), 0.1016 * std::f64::consts::PI);
+    assert_close(
+        wheel.distance(Degree::new(180.0)).value(),
+        0.0508 * std::f64::consts::PI,
+    );
+    assert_close(
+        wheel.angle::<Rotation>(wheel.circumference() * 3.0).value(),
+        3.0,
+    );
+    let speed = wheel.velocity(RotationPerSec::new(10.0));
+    assert_close(speed.value(), 1.016 * std::f64::consts::PI);
+    assert_close(
+        wheel.angular_velocity::<RotationPerSec>(speed).value(),
+        10.0,
+    );
+
+    let gearing = GearRatio::from_teeth(14, 50).then(GearRatio::from_teeth(16, 28));
+    assert_close(gearing.reduction(), 6.25);
+    assert_close(
+        gearing.output_velocity(RotationPerMin::new(6000.0)).value(),
+        960.0,
+    );
+    assert_close(gearing.inverse().output(Rotation::new(2.0)).value(), 12.5);
+    assert_close(gearing.output_torque(NewtonMeter::new(2.0)).value(), 12.5);
+    assert_close(gearing.input_torque(NewtonMeter::new(12.5)).value(), 2.0);
+
+    let encoder = Encoder::new(2048.0);
+    assert_close(encoder.angle(512.0).value(), 0.25);
+    assert_close(encoder.ticks(Degree::new(90.0)), 512.0);
+    let velocity = encoder.velocity(2048.0, Millisecond::new(100.0));
+    assert_close(velocity.value(), 10.0);
+    assert_close(encoder.ticks_per(velocity, Millisecond::new(100.0)), 2048.0);
+}
+
+#[test]
+fn mechanism_transforms() {
+    use crate::units::{
+        angle::{Degree, Radian, Rotation},
+        angular_acceleration::RotationPerSecSqr,
+        angular_velocity::{RotationPerMin, RotationPerSec},
+        frc::{GearRatio, Wheel},
+        length::Inch,
+        linear_velocity::FeetPerSecond,
+        mechanism::{Mechanism, Offset, Radius},
+    };
+
+    let drive = GearRatio::new(6.75).chain(Wheel::new(Inch::new(4.0)));
+    let circumference = 0.1016 * std::f64::consts::PI;
+    assert_close(drive.position(Rotation::new(6.75)).value(), circumference);
+    assert_close(
+        drive.velocity(RotationPerMin::new(6075.0)).value(),
+        15.0 * circumference,
+    );
+    assert_close(
+        drive.acceleration(RotationPerSecSqr::new(13.5)).value(),
+        2.0 * circumference,
+    );
+    assert_close(
+        RotationPerSec::from(drive.inverse().velocity(FeetPerSecond::new(10.0))).value(),
+        3.048 / circumference * 6.75,
+    );
+
+    let arm = GearRatio::new(100.0)
+        .chain(Offset::new(Degree::new(-45.0)))
+        .chain(Radius::new(Inch::new(20.0)));
+    let tip = arm.position(Rotation::new(0.0));
+    assert_close(tip.value(), -0.508 * std::f64::consts::FRAC_PI_4);
+    assert!(Rotation::from(arm.inverse().position(tip)).value().abs() < 1e-12);
+    // offsets move positions but never velocities
+    assert_close(
+        arm.velocity(RotationPerSec::new(100.0)).value(),
+        0.508 * std::f64::consts::TAU,
+    );
+    assert_close(
+        Offset::<Radian>::new(Radian::new(1.0))
+            .inverse()
+            .position(Radian::new(1.0))
+            .value(),
+        0.0,
+    );
+
+    // any unit with velocity and acceleration relations can be a position
+    let raised = Offset::<Foot>::new(Foot::new(2.0));
+    assert_close(raised.position(Foot::new(1.0)).value(), 3.0);
+    let speed: FeetPerSecond = raised.velocity(FeetPerSecond::new(4.0));
+    assert_close(speed.value(), 4.0);
+}
+
+#[test]
+fn measured_values() {
+    use crate::units::{
+        linear_velocity::MetersPerSecond,
+        measured::Measured,
+        temperature::{Celsius, CelsiusDelta, Fahrenheit, FahrenheitDelta},
+        time::Second,
+    };
+
+    let a = Measured::new(Meter::new(4.0), Meter::new(0.3));
+    let b = Measured::new(Meter::new(1.0), Meter::new(-0.4));
+    assert_eq!(b.std_dev(), Meter::new(0.4));
+    assert_close((a + b).std_dev().value(), 0.5);
+    assert_close((a - b).value().value(), 3.0);
+    assert_close((a - b).std_dev().value(), 0.5);
+    assert_close((-a * 2.0).std_dev().value(), 0.6);
+    assert_close(
+        Measured::from_variance(Meter::new(1.0), 0.25).variance(),
+        0.25,
+    );
+
+    let speed = Measured::new(MetersPerSecond::new(2.0), MetersPerSecond::new(0.1));
+    let time = Measured::new(Second::new(3.0), Second::new(0.2));
+    let traveled: Measured<Meter> = speed * time;
+    assert_close(traveled.value().value(), 6.0);
+    assert_close(traveled.std_dev().value(), 0.3f64.hypot(0.4));
+    let back: Measured<MetersPerSecond> = traveled / Measured::exact(Second::new(3.0));
+    assert_close(back.std_dev().value(), 0.5 / 3.0);
+
+    let feet = a.convert::<Foot>();
+    assert_close(feet.value().value(), 4.0 / 0.3048);
+    assert_close(feet.std_dev().value(), 0.3 / 0.3048);
+    let warm = Measured::new(Celsius::new(20.0), CelsiusDelta::new(0.5)).convert::<Fahrenheit>();
+    assert_close(warm.value().value(), 68.0);
+    assert_close(warm.std_dev().value(), 0.9);
+
+    let cold = Measured::new(Celsius::new(5.0), CelsiusDelta::new(1.2));
+    let rise: Measured<CelsiusDelta> =
+        Measured::new(Celsius::new(20.0), CelsiusDelta::new(0.5)) - cold;
+    assert_close(rise.value().value(), 15.0);
+    assert_close(rise.std_dev().value(), 1.3);
+    let warmed: Measured<Celsius> =
+        cold + Measured::new(FahrenheitDelta::new(9.0), FahrenheitDelta::new(0.9));
+    assert_close(warmed.value().value(), 10.0);
+    assert_close(warmed.std_dev().value(), 1.2f64.hypot(0.5));
+
+    let fused = Measured::new(Meter::new(1.0), Meter::new(0.3))
+        .fuse(Measured::new(Meter::new(2.0), Meter::new(0.4)));
+    assert_close(fused.value().value(), 1.36);
+    assert_close(fused.std_dev().value(), 0.24);
+    assert_eq!(
+        a.fuse(Measured::exact(Meter::new(5.0))).value(),
+        Meter::new(5.0)
+    );
+}
@@ -0,0 +1,103 @@
+use std::fmt::{self, Display};
+
+use thiserror::Error;
+
+use super::Unit;
+
+/// An error that occurs when parsing a unit from text like `"3.5 ft"`
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UnitParseError {
+    #[error("`{0}` does not start with a valid number")]
+    InvalidNumber(String),
+    #[error("`{suffix}` is not a known unit of {expected}")]
+    UnknownSuffix {
+        suffix: String,
+        expected: &'static str,
+    },
+}
+
+/// A unit value formatted with its symbol, created by a unit's `display` method.
+///
+/// The precision of the formatter is applied to the number,
+/// so `format!("{:.1}", Foot::new(3.25).display())` gives `"3.2 ft"`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitDisplay<T> {
+    value: T,
+    symbol: &'static str,
+}
+
+impl<T> UnitDisplay<T> {
+    /// Pairs a value with the symbol it is measured in
+    #[must_use]
+    pub const fn new(value: T, symbol: &'static str) -> Self {
+        Self { value, symbol }
+    }
+
+    /// The symbol this value is displayed with
+    #[must_use]
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+}
+
+impl<T: Display> Display for UnitDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)?;
+        write!(f, " {}", self.symbol)
+    }
+}
+
+/// Parsing text in any unit of a family into a specific unit,
+/// implemented for every unit of a [`unit_family!`](crate::unit_family).
+pub trait ParseFamily: Unit {
+    /// Parses text like `12in` or `3.5 ft` in any unit of this unit's family
+    /// and converts it to this unit.
+    ///
+    /// # Errors
+    /// Returns an error if the text does not start with a number
+    /// or the suffix is not a unit of the family.
+    fn parse_family(text: &str) -> Result<Self, UnitParseError>;
+
+    /// Converts `value` measured in the unit named by `suffix` to this unit,
+    /// an empty suffix is read in the standard unit of the family.
+    ///
+    /// # Errors
+    /// Returns an error if the suffix is not a unit of the family
+    /// or the value is out of range for the unit.
+    fn from_family(value: f64, suffix: &str) -> Result<Self, UnitParseError>;
+}
+
+/// Returns true if `suffix` names the unit `U`.
+///
+/// The symbol must match exactly as `mA` and `MA` are different units,
+/// the name and aliases are matched ignoring ascii case.
+#[must_use]
+pub fn matches_suffix<U: Unit>(suffix: &str) -> bool {
+    suffix == U::SYMBOL
+        || suffix.eq_ignore_ascii_case(U::NAME)
+        || U::ALIASES
+            .iter()
+            .any(|alias| suffix.eq_ignore_ascii_case(alias))
+}
+
+/// Splits text like `"12in"` or `"-3.5e2 ft"` into the longest leading number and the trimmed suffix.
+///
+/// # Errors
+/// Returns an error if no prefix of the text parses as `T`.
+pub fn split_number<T: std::str::FromStr>(text: &str) -> Result<(T, &str), UnitParseError> {
+    let text = text.trim();
+    text.char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()))
+        .rev()
+        .find_map(|index| {
+            text[..index]
+                .trim_end()
+                .parse()
+                .ok()
+                .map(|number| (number, text[index..].trim()))
+        })
+        .ok_or_else(|| UnitParseError::InvalidNumber(text.to_owned()))
+}
@@ -1,22 +1,23 @@
 use std::{ops::Neg, time::Duration};
 
-use crate::{unit, unit_conversion, unit_family};
+use crate::units::quantity::dims;
+use crate::{unit, unit_conversion, unit_family, unit_quantity};
 
-unit!(Hour: float);
-unit!(Minute: float);
-unit!(Second: float);
-unit!(Millisecond: float);
-unit!(Microsecond: uint);
+unit!(Hour: float = "h", "hr", "hours");
+unit!(Minute: float = "min", "minutes");
+unit!(Second: float = "s", "sec", "seconds");
+unit!(Millisecond: float = "ms");
+unit!(Microsecond: uint = "µs", "us");
 
-unit_conversion!(Second(float) <-> Millisecond(float) ~ second_to_millisecond);
+unit_conversion!(Second(float) <-> Millisecond(float) ~ si);
 unit_conversion!(Second(float) <-> Microsecond(uint) ~ second_to_microsecond);
 unit_conversion!(Millisecond(float) <-> Microsecond(uint) ~ millisecond_to_microsecond);
-unit_conversion!(Hour(float) <-> Second(float) ~ hour_to_second);
-unit_conversion!(Minute(float) <-> Second(float) ~ minute_to_second);
-unit_conversion!(Hour(float) <-> Minute(float) ~ hour_to_minute);
-unit_conversion!(Minute(float) <-> Millisecond(float) ~ minute_to_millisecond);
+unit_conversion!(Hour(float) <-> Second(float) ~ si);
+unit_conversion!(Minute(float) <-> Second(float) ~ si);
+unit_conversion!(Hour(float) <-> Minute(float) ~ si);
+unit_conversion!(Minute(float) <-> Millisecond(float) ~ si);
 unit_conversion!(Minute(float) <-> Microsecond(uint) ~ minute_to_microsecond);
-unit_conversion!(Hour(float) <-> Millisecond(float) ~ hour_to_millisecond);
+unit_conversion!(Hour(float) <-> Millisecond(float) ~ si);
 unit_conversion!(Hour(float) <-> Microsecond(uint) ~ hour_to_microsecond);
 
 //TODO: This is a hack to satisfy unit family
@@ -28,61 +29,33 @@ impl Neg for Microsecond {
     }
 }
 
-unit_family!(Time(Second): Hour, Minute, Millisecond, Microsecond);
+unit_family!(Time(Second): Hour, Minute, Millisecond, Microsecond; scale: Microsecond, Millisecond, Second, Minute, Hour);
 
-fn second_to_millisecond(second: f64) -> f64 {
-    second * 1000.0
-}
+unit_quantity!(Hour: dims::Time = 3600.0);
+unit_quantity!(Minute: dims::Time = 60.0);
+unit_quantity!(Second: dims::Time = 1.0);
+unit_quantity!(Millisecond: dims::Time = 0.001);
 
+// every microsecond conversion goes through seconds and rounds to the nearest microsecond
 #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
 fn second_to_microsecond(second: f64) -> u64 {
     if second.is_sign_negative() {
         0
     } else {
-        (second * 1_000_000.0) as u64
+        (second * 1_000_000.0).round() as u64
     }
 }
 
-#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
 fn millisecond_to_microsecond(millisecond: f64) -> u64 {
-    if millisecond.is_sign_negative() {
-        0
-    } else {
-        (millisecond * 1000.0) as u64
-    }
+    second_to_microsecond(millisecond / 1000.0)
 }
 
-fn hour_to_second(hour: f64) -> f64 {
-    hour * 3600.0
-}
-
-fn minute_to_second(minute: f64) -> f64 {
-    minute * 60.0
-}
-
-fn hour_to_minute(hour: f64) -> f64 {
-    hour * 60.0
-}
-
-fn minute_to_millisecond(minute: f64) -> f64 {
-    minute * 60000.0
-}
-
-#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
 fn minute_to_microsecond(minute: f64) -> u64 {
-    if minute.is_sign_negative() {
-        0
-    } else {
-        (minute * 60_000_000.0) as u64
-    }
-}
-
-fn hour_to_millisecond(hour: f64) -> f64 {
-    second_to_millisecond(hour_to_second(hour))
+    second_to_microsecond(minute * 60.0)
 }
 
 fn hour_to_microsecond(hour: f64) -> u64 {
-    second_to_microsecond(hour_to_second(hour))
+    second_to_microsecond(hour * 3600.0)
 }
 
 impl From<Duration> for Hour {
@@ -0,0 +1,11 @@
+use crate::units::current::Amp;
+use crate::units::quantity::dims;
+use crate::units::resistance::Ohm;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+unit!(Volt | Volts: float = "V");
+unit!(Millivolt | Millivolts: float = "mV");
+
+unit_family!(Voltage(Volt) in dims::Voltage: Millivolt = 0.001; scale: Millivolt, Volt);
+
+unit_dim_analysis!(Amp * Ohm = Volt);
@@ -0,0 +1,25 @@
+use crate::units::area::SquareMeter;
+use crate::units::length::Meter;
+use crate::units::quantity::dims;
+use crate::{unit, unit_dim_analysis, unit_family};
+
+/// One cubic inch in cubic meters
+const CUBIC_METERS_PER_CUBIC_INCH: f64 = 0.0254 * 0.0254 * 0.0254;
+/// One cubic foot in cubic meters
+const CUBIC_METERS_PER_CUBIC_FOOT: f64 = 0.3048 * 0.3048 * 0.3048;
+
+unit!(CubicMeter | CubicMeters: float = "m³", "m^3");
+unit!(Liter | Liters: float = "L", "l");
+unit!(Milliliter | Milliliters: float = "mL", "ml");
+unit!(CubicInch | CubicInches: float = "in³", "in^3");
+unit!(CubicFoot | CubicFeet: float = "ft³", "ft^3");
+
+unit_family!(Volume(CubicMeter) in dims::Volume:
+    Liter = 0.001,
+    Milliliter = 0.000_001,
+    CubicInch = CUBIC_METERS_PER_CUBIC_INCH,
+    CubicFoot = CUBIC_METERS_PER_CUBIC_FOOT;
+    scale: Milliliter, Liter, CubicMeter
+);
+
+unit_dim_analysis!(SquareMeter * Meter = CubicMeter);
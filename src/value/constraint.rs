@@ -0,0 +1,447 @@
+//! Constraints on the contents of an [``FrcValue``](super::FrcValue) beyond its type
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use super::{FrcType, FrcValue, StaticallyFrcTyped};
+
+/// An error describing which constraint an [``FrcValue``] did not satisfy
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FrcConstraintError {
+    #[error("Expected a {expected} value but found {found}")]
+    WrongType { expected: FrcType, found: FrcType },
+    #[error("Expected a struct of type {expected} but found {found}")]
+    WrongStruct { expected: Box<str>, found: FrcType },
+    #[error("{value} is outside of the range {min} to {max}")]
+    OutOfRange { value: f64, min: f64, max: f64 },
+    #[error("Length {len} is outside of the range {min} to {max}")]
+    WrongLength { len: usize, min: usize, max: usize },
+    #[error("`{0}` is not one of the allowed values")]
+    NotAllowed(Box<str>),
+    #[error("Value is NaN")]
+    NaN,
+    #[error("A {constraint} constraint cannot be applied to a {found} value")]
+    NotApplicable {
+        constraint: &'static str,
+        found: FrcType,
+    },
+    #[error("Element {index}: {source}")]
+    Element { index: usize, source: Box<Self> },
+}
+
+/// A single rule an [``FrcValue``] can be checked against
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrcConstraint {
+    /// The value must be exactly this type
+    Type(FrcType),
+    /// The value must be a struct or struct array of the type with this name
+    StructType(Box<str>),
+    /// Numbers and every element of numeric arrays must be within `min..=max`,
+    /// NaN is never within range
+    Range {
+        /// The inclusive lower bound
+        min: f64,
+        /// The inclusive upper bound
+        max: f64,
+    },
+    /// Arrays and raw data must have a length within `min..=max`
+    Length {
+        /// The inclusive lower bound
+        min: usize,
+        /// The inclusive upper bound
+        max: usize,
+    },
+    /// Strings and every element of string arrays must be one of these values
+    OneOf(Box<[Box<str>]>),
+    /// Doubles, floats and their arrays must not contain NaN
+    NotNaN,
+}
+
+/// Compares types by variant and struct type name
+fn type_matches(expected: FrcType, found: FrcType) -> bool {
+    match (expected, found) {
+        (FrcType::Struct(a), FrcType::Struct(b))
+        | (FrcType::StructArray(a), FrcType::StructArray(b)) => a.type_str == b.type_str,
+        _ => expected == found,
+    }
+}
+
+/// Checks every element of an array, reporting the index of the first failure
+fn check_elements<T>(
+    values: &[T],
+    check: impl Fn(&T) -> Result<(), FrcConstraintError>,
+) -> Result<(), FrcConstraintError> {
+    values.iter().enumerate().try_for_each(|(index, value)| {
+        check(value).map_err(|source| FrcConstraintError::Element {
+            index,
+            source: Box::new(source),
+        })
+    })
+}
+
+/// Checks a numeric value against a range, None if the value is not numeric
+#[allow(clippy::cast_precision_loss)]
+fn check_range(value: &FrcValue, min: f64, max: f64) -> Option<Result<(), FrcConstraintError>> {
+    let check = |v: f64| {
+        if (min..=max).contains(&v) {
+            Ok(())
+        } else {
+            Err(FrcConstraintError::OutOfRange { value: v, min, max })
+        }
+    };
+    Some(match value {
+        FrcValue::Int(v) => check(*v as f64),
+        FrcValue::Double(v) => check(*v),
+        FrcValue::Float(v) => check(f64::from(*v)),
+        FrcValue::IntArray(v) => check_elements(v, |v| check(*v as f64)),
+        FrcValue::DoubleArray(v) => check_elements(v, |v| check(*v)),
+        FrcValue::FloatArray(v) => check_elements(v, |v| check(f64::from(*v))),
+        _ => return None,
+    })
+}
+
+/// Checks a numeric value for NaN, None if the value is not numeric
+fn check_not_nan(value: &FrcValue) -> Option<Result<(), FrcConstraintError>> {
+    let check = |is_nan: bool| {
+        if is_nan {
+            Err(FrcConstraintError::NaN)
+        } else {
+            Ok(())
+        }
+    };
+    Some(match value {
+        FrcValue::Int(_) | FrcValue::IntArray(_) => Ok(()),
+        FrcValue::Double(v) => check(v.is_nan()),
+        FrcValue::Float(v) => check(v.is_nan()),
+        FrcValue::DoubleArray(v) => check_elements(v, |v| check(v.is_nan())),
+        FrcValue::FloatArray(v) => check_elements(v, |v| check(v.is_nan())),
+        _ => return None,
+    })
+}
+
+impl FrcConstraint {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Type(_) => "type",
+            Self::StructType(_) => "struct type",
+            Self::Range { .. } => "range",
+            Self::Length { .. } => "length",
+            Self::OneOf(_) => "one of",
+            Self::NotNaN => "not NaN",
+        }
+    }
+
+    /// Checks the value against this constraint
+    ///
+    /// # Errors
+    /// Returns an error describing why the value does not satisfy the constraint
+    pub fn validate(&self, value: &FrcValue) -> Result<(), FrcConstraintError> {
+        let not_applicable = || FrcConstraintError::NotApplicable {
+            constraint: self.name(),
+            found: value.get_type(),
+        };
+        match self {
+            Self::Type(expected) => {
+                if type_matches(*expected, value.get_type()) {
+                    Ok(())
+                } else {
+                    Err(FrcConstraintError::WrongType {
+                        expected: *expected,
+                        found: value.get_type(),
+                    })
+                }
+            }
+            Self::StructType(expected) => match value {
+                FrcValue::Struct(bytes) | FrcValue::StructArray(bytes)
+                    if bytes.desc.type_str == expected.as_ref() =>
+                {
+                    Ok(())
+                }
+                _ => Err(FrcConstraintError::WrongStruct {
+                    expected: expected.clone(),
+                    found: value.get_type(),
+                }),
+            },
+            Self::Range { min, max } => {
+                check_range(value, *min, *max).unwrap_or_else(|| Err(not_applicable()))
+            }
+            Self::Length { min, max } => {
+                let len = match value {
+                    FrcValue::Raw(v) => v.len(),
+                    FrcValue::BooleanArray(v) => v.len(),
+                    FrcValue::IntArray(v) => v.len(),
+                    FrcValue::FloatArray(v) => v.len(),
+                    FrcValue::DoubleArray(v) => v.len(),
+                    FrcValue::StringArray(v) => v.len(),
+                    FrcValue::StructArray(bytes) => bytes.count,
+                    _ => return Err(not_applicable()),
+                };
+                if (*min..=*max).contains(&len) {
+                    Ok(())
+                } else {
+                    Err(FrcConstraintError::WrongLength {
+                        len,
+                        min: *min,
+                        max: *max,
+                    })
+                }
+            }
+            Self::OneOf(allowed) => {
+                let check = |v: &str| {
+                    if allowed.iter().any(|a| a.as_ref() == v) {
+                        Ok(())
+                    } else {
+                        Err(FrcConstraintError::NotAllowed(v.into()))
+                    }
+                };
+                match value {
+                    FrcValue::String(v) => check(v),
+                    FrcValue::StringArray(v) => check_elements(v, |v| check(v)),
+                    _ => Err(not_applicable()),
+                }
+            }
+            Self::NotNaN => check_not_nan(value).unwrap_or_else(|| Err(not_applicable())),
+        }
+    }
+}
+
+/// A set of [``FrcConstraint``]s that a value must satisfy all of
+///
+/// # Example
+/// ```
+/// use frclib_core::value::{FrcConstraints, FrcType, FrcValue};
+///
+/// let voltage = FrcConstraints::new()
+///     .of_type(FrcType::Double)
+///     .range(0.0, 12.0)
+///     .not_nan();
+/// assert!(voltage.validate(&FrcValue::Double(6.0)).is_ok());
+/// assert!(voltage.validate(&FrcValue::Double(13.0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FrcConstraints {
+    constraints: Vec<FrcConstraint>,
+    allow_void: bool,
+}
+
+impl FrcConstraints {
+    /// Creates an empty set of constraints, every value except ``Void`` is valid
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            constraints: Vec::new(),
+            allow_void: false,
+        }
+    }
+
+    /// Adds a constraint to the set
+    #[must_use]
+    pub fn with(mut self, constraint: FrcConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Adds every constraint of another set to this one
+    #[must_use]
+    pub fn with_all(mut self, other: Self) -> Self {
+        self.constraints.extend(other.constraints);
+        self.allow_void |= other.allow_void;
+        self
+    }
+
+    /// The value must be exactly this type
+    #[must_use]
+    pub fn of_type(self, frc_type: FrcType) -> Self {
+        self.with(FrcConstraint::Type(frc_type))
+    }
+
+    /// The value must be a struct or struct array of the type with this name
+    #[must_use]
+    pub fn struct_type(self, type_str: impl Into<Box<str>>) -> Self {
+        self.with(FrcConstraint::StructType(type_str.into()))
+    }
+
+    /// Numbers and every element of numeric arrays must be within `min..=max`
+    #[must_use]
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.with(FrcConstraint::Range { min, max })
+    }
+
+    /// Arrays and raw data must have a length within `min..=max`
+    #[must_use]
+    pub fn length(self, min: usize, max: usize) -> Self {
+        self.with(FrcConstraint::Length { min, max })
+    }
+
+    /// Arrays and raw data must have exactly this length
+    #[must_use]
+    pub fn exact_length(self, len: usize) -> Self {
+        self.length(len, len)
+    }
+
+    /// Strings and every element of string arrays must be one of these values
+    #[must_use]
+    pub fn one_of<S: Into<Box<str>>>(self, allowed: impl IntoIterator<Item = S>) -> Self {
+        self.with(FrcConstraint::OneOf(
+            allowed.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Doubles, floats and their arrays must not contain NaN
+    #[must_use]
+    pub fn not_nan(self) -> Self {
+        self.with(FrcConstraint::NotNaN)
+    }
+
+    /// Allows ``Void`` values to skip every constraint
+    #[must_use]
+    pub const fn allow_void(mut self) -> Self {
+        self.allow_void = true;
+        self
+    }
+
+    /// The constraints in this set in the order they are checked
+    #[must_use]
+    pub fn constraints(&self) -> &[FrcConstraint] {
+        &self.constraints
+    }
+
+    /// Checks the value against every constraint in order
+    ///
+    /// # Errors
+    /// Returns the error of the first constraint the value does not satisfy
+    pub fn validate(&self, value: &FrcValue) -> Result<(), FrcConstraintError> {
+        if matches!(value, FrcValue::Void) {
+            return self.check_void();
+        }
+        self.constraints
+            .iter()
+            .try_for_each(|constraint| constraint.validate(value))
+    }
+
+    /// Checks the value against every constraint and collects every failure
+    #[must_use]
+    pub fn validate_all(&self, value: &FrcValue) -> Vec<FrcConstraintError> {
+        if matches!(value, FrcValue::Void) {
+            return self.check_void().err().into_iter().collect();
+        }
+        self.constraints
+            .iter()
+            .filter_map(|constraint| constraint.validate(value).err())
+            .collect()
+    }
+
+    const fn check_void(&self) -> Result<(), FrcConstraintError> {
+        if self.allow_void {
+            Ok(())
+        } else {
+            Err(FrcConstraintError::NotApplicable {
+                constraint: "non void",
+                found: FrcType::Void,
+            })
+        }
+    }
+}
+
+/// A type whose [``FrcValue``] form has constraints beyond its [``FrcType``]
+///
+/// # Example
+/// ```
+/// use frclib_core::value::{FrcConstrained, FrcConstraints, FrcType, FrcValue, StaticallyFrcTyped};
+///
+/// struct ArmSetpoint(f64);
+/// impl From<ArmSetpoint> for FrcValue {
+///     fn from(value: ArmSetpoint) -> Self {
+///         Self::Double(value.0)
+///     }
+/// }
+/// impl StaticallyFrcTyped for ArmSetpoint {
+///     const TYPE: FrcType = FrcType::Double;
+/// }
+/// impl FrcConstrained for ArmSetpoint {
+///     fn constraints() -> FrcConstraints {
+///         FrcConstraints::new().range(-0.5, 2.0).not_nan()
+///     }
+/// }
+///
+/// assert!(ArmSetpoint::validate(&FrcValue::Double(1.0)).is_ok());
+/// assert!(ArmSetpoint::validate(&FrcValue::Int(1)).is_err());
+/// ```
+pub trait FrcConstrained: StaticallyFrcTyped {
+    /// The constraints values of this type must satisfy,
+    /// the [``StaticallyFrcTyped::TYPE``] is checked before these
+    fn constraints() -> FrcConstraints;
+
+    /// Checks that the value is of this type and satisfies its constraints
+    ///
+    /// # Errors
+    /// Returns the error of the first constraint the value does not satisfy
+    fn validate(value: &FrcValue) -> Result<(), FrcConstraintError> {
+        if Self::COULD_BE_VOID && matches!(value, FrcValue::Void) {
+            return Ok(());
+        }
+        FrcConstraint::Type(Self::TYPE).validate(value)?;
+        Self::constraints().validate(value)
+    }
+}
+
+/// A collection of [``FrcConstraints``] attached to keys,
+/// keys without constraints accept any value
+#[derive(Debug, Clone, Default)]
+pub struct FrcKeyConstraints {
+    map: HashMap<Box<str>, FrcConstraints>,
+}
+
+impl FrcKeyConstraints {
+    /// Creates an empty collection
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches constraints to a key, returning any that were already attached
+    pub fn insert(
+        &mut self,
+        key: impl Into<Box<str>>,
+        constraints: FrcConstraints,
+    ) -> Option<FrcConstraints> {
+        self.map.insert(key.into(), constraints)
+    }
+
+    /// Attaches the constraints of a type to a key, returning any that were already attached
+    pub fn insert_typed<T: FrcConstrained>(
+        &mut self,
+        key: impl Into<Box<str>>,
+    ) -> Option<FrcConstraints> {
+        let mut constraints = FrcConstraints::new()
+            .of_type(T::TYPE)
+            .with_all(T::constraints());
+        if T::COULD_BE_VOID {
+            constraints = constraints.allow_void();
+        }
+        self.insert(key, constraints)
+    }
+
+    /// Removes the constraints attached to a key
+    pub fn remove(&mut self, key: &str) -> Option<FrcConstraints> {
+        self.map.remove(key)
+    }
+
+    /// Gets the constraints attached to a key
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&FrcConstraints> {
+        self.map.get(key)
+    }
+
+    /// Checks the value against the constraints attached to the key
+    ///
+    /// # Errors
+    /// Returns the error of the first constraint the value does not satisfy
+    pub fn validate(&self, key: &str, value: &FrcValue) -> Result<(), FrcConstraintError> {
+        self.map
+            .get(key)
+            .map_or(Ok(()), |constraints| constraints.validate(value))
+    }
+}
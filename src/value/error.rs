@@ -2,12 +2,19 @@ use thiserror::Error;
 
 use super::FrcType;
 
+/// The reason a cast between [``FrcValue``](super::FrcValue) and another type failed
 #[derive(Debug, Clone, Copy)]
 pub enum CastErrorReason {
+    /// The value was of an incompatible type
     Type,
+    /// The value was too large to be represented by the target type
     Overflow,
+    /// The value was too small to be represented by the target type
     Underflow,
+    /// The value was of the correct type but its contents could not be decoded
     Deserialization,
+    /// The value was tagged with a unit that is not part of the target unit family
+    UnknownUnit,
 }
 
 /// An error that occurs when casting between [``FrcValue``](super::FrcValue) and other types
@@ -21,3 +28,47 @@ pub enum FrcValueCastError {
     #[error("Could not represent the casted data as an FrcValue")]
     UnrepresentableCast,
 }
+
+/// An error that occurs when parsing an [``FrcValue``](super::FrcValue) from its text literal form
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FrcValueParseError {
+    #[error("Expected {0} but reached the end of the input")]
+    UnexpectedEnd(&'static str),
+    #[error("Expected {expected} but found `{found}` at {position}")]
+    UnexpectedChar {
+        position: usize,
+        found: char,
+        expected: &'static str,
+    },
+    #[error("`{0}` is not a valid number")]
+    InvalidNumber(String),
+    #[error("Invalid escape sequence at {0}")]
+    InvalidEscape(usize),
+    #[error("Raw data is not valid {0}")]
+    InvalidRaw(&'static str),
+    #[error("Array elements must all be of the same type")]
+    MixedArray,
+    #[error("The type of an empty array must be given, e.g. `int[]`")]
+    UntypedEmptyArray,
+    #[error("`{0}` is not a known struct type")]
+    UnknownStruct(String),
+    #[error("Expected a `{expected}` struct but found `{found}`")]
+    MismatchedStruct { expected: String, found: String },
+    #[error(transparent)]
+    Schema(#[from] crate::structure::FrcSchemaError),
+    #[error("Struct `{0}` has no field `{1}`")]
+    UnknownField(String, String),
+    #[error("Struct `{0}` is missing field `{1}`")]
+    MissingField(String, String),
+    #[error("Struct `{0}` sets field `{1}` more than once")]
+    DuplicateField(String, String),
+    #[error("Field `{0}` expects {1} elements but {2} were given")]
+    ArrayLength(String, usize, usize),
+    #[error("`{0}` is out of range for {1}")]
+    OutOfRange(String, &'static str),
+    #[error("`{0}` is not a variant of field `{1}`")]
+    UnknownVariant(String, String),
+    #[error("Struct `{0}` is {1} bytes but {2} bytes were given")]
+    StructSize(String, usize, usize),
+}
@@ -6,21 +6,27 @@ use std::{
     io::Cursor,
 };
 
+mod constraint;
 mod error;
 #[cfg(test)]
 mod test;
+mod text;
 mod trait_impls;
 mod traits;
 
 use crate::structure::{FrcStructDesc, FrcStructure, FrcStructureBytes};
-pub use error::FrcValueCastError;
+pub use constraint::{
+    FrcConstrained, FrcConstraint, FrcConstraintError, FrcConstraints, FrcKeyConstraints,
+};
+pub use error::{CastErrorReason, FrcValueCastError, FrcValueParseError};
+pub use text::FrcValueLiteral;
+use text::StructStyle;
 pub use traits::IntoFrcValue;
 pub use traits::StaticallyFrcTyped;
 
+pub use frclib_structure_macros::IntoFrcValue;
 pub use inventory;
 
-use self::error::CastErrorReason;
-
 /// Measured in microseconds
 ///
 /// depending on source can be from unix epoch or some arbitrary start time
@@ -132,12 +138,28 @@ impl Display for FrcValue {
             Self::DoubleArray(v) => write!(f, "{v:?}"),
             Self::StringArray(v) => write!(f, "{v:?}"),
             Self::Raw(v) => write!(f, "{v:?}"),
-            Self::Struct(bytes) => write!(f, "Struct({}):{:?}", bytes.desc.type_str, bytes.data),
-            Self::StructArray(bytes) => write!(
-                f,
-                "Struct({})[{}]:{:?}",
-                bytes.desc.type_str, bytes.count, bytes.data
-            ),
+            Self::Struct(bytes) => {
+                let mut buffer = String::new();
+                if text::write_struct(&mut buffer, bytes.desc, &bytes.data, StructStyle::Display)
+                    .is_some()
+                {
+                    f.write_str(&buffer)
+                } else {
+                    write!(f, "Struct({}):{:?}", bytes.desc.type_str, bytes.data)
+                }
+            }
+            Self::StructArray(bytes) => {
+                let mut buffer = String::new();
+                if text::write_struct_array(&mut buffer, bytes, StructStyle::Display).is_some() {
+                    f.write_str(&buffer)
+                } else {
+                    write!(
+                        f,
+                        "Struct({})[{}]:{:?}",
+                        bytes.desc.type_str, bytes.count, bytes.data
+                    )
+                }
+            }
         }
     }
 }
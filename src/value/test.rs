@@ -1,4 +1,4 @@
-use crate::value::{FrcValue, IntoFrcValue};
+use crate::value::{CastErrorReason, FrcValue, IntoFrcValue};
 
 const _: fn() = || {
     let _ = core::mem::transmute::<FrcValue, [u8; 24]>;
@@ -52,3 +52,451 @@ fn test_into_frc_value() {
     assert_frc_value(None::<i64>, FrcValue::Void);
     assert_frc_value(Some(1), FrcValue::Int(1));
 }
+
+#[test]
+fn test_derive_into_frc_value() {
+    use crate as frclib_core;
+    use crate::value::{FrcType, FrcValueCastError, StaticallyFrcTyped};
+
+    #[derive(Debug, PartialEq, Clone, Copy, super::IntoFrcValue)]
+    enum AutoRoutine {
+        DoNothing,
+        ScoreTwo,
+        ScoreThree,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, super::IntoFrcValue)]
+    #[IntoFrcValue(int)]
+    enum Level {
+        Low = 1,
+        Mid,
+        High = 10,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, super::IntoFrcValue)]
+    struct Voltage(f64);
+
+    #[derive(Debug, PartialEq, Clone, super::IntoFrcValue)]
+    struct Name {
+        inner: String,
+    }
+
+    assert_eq!(AutoRoutine::TYPE, FrcType::String);
+    assert_eq!(
+        FrcValue::from(AutoRoutine::ScoreTwo),
+        FrcValue::String(Box::from("ScoreTwo"))
+    );
+    assert_eq!(
+        AutoRoutine::try_from(FrcValue::String(Box::from("ScoreThree"))).ok(),
+        Some(AutoRoutine::ScoreThree)
+    );
+    assert!(matches!(
+        AutoRoutine::try_from(FrcValue::String(Box::from("ScoreFour"))),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::String,
+            "AutoRoutine",
+            CastErrorReason::Deserialization
+        ))
+    ));
+    assert!(matches!(
+        AutoRoutine::try_from(FrcValue::Int(0)),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::Int,
+            "AutoRoutine",
+            CastErrorReason::Type
+        ))
+    ));
+
+    assert_eq!(Level::TYPE, FrcType::Int);
+    assert_eq!(FrcValue::from(Level::Mid), FrcValue::Int(2));
+    assert_eq!(Level::try_from(FrcValue::Int(10)).ok(), Some(Level::High));
+    assert!(matches!(
+        Level::try_from(FrcValue::Int(3)),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::Int,
+            "Level",
+            CastErrorReason::Deserialization
+        ))
+    ));
+
+    assert_eq!(Voltage::TYPE, FrcType::Double);
+    assert_eq!(Voltage(12.0).into_frc_value(), FrcValue::Double(12.0));
+    assert_eq!(
+        Voltage::try_from(FrcValue::Float(6.0)).ok(),
+        Some(Voltage(6.0))
+    );
+    assert!(matches!(
+        Voltage::try_from(FrcValue::Boolean(true)),
+        Err(FrcValueCastError::InvalidCastTo(
+            FrcType::Boolean,
+            "Voltage",
+            CastErrorReason::Type
+        ))
+    ));
+
+    assert_eq!(Name::TYPE, FrcType::String);
+    assert_eq!(
+        Name::try_from(FrcValue::String(Box::from("frc"))).ok(),
+        Some(Name {
+            inner: "frc".to_owned()
+        })
+    );
+}
+
+#[test]
+fn test_literal_round_trip() {
+    use crate::value::FrcValueParseError;
+
+    fn round_trip(literal: &str, value: &FrcValue) {
+        assert_eq!(value.literal().to_string(), literal);
+        assert_eq!(literal.parse::<FrcValue>().as_ref(), Ok(value));
+    }
+
+    round_trip("void", &FrcValue::Void);
+    round_trip("true", &FrcValue::Boolean(true));
+    round_trip("-20", &FrcValue::Int(-20));
+    round_trip("1.0", &FrcValue::Double(1.0));
+    round_trip("1e-7", &FrcValue::Double(1e-7));
+    round_trip("-inf", &FrcValue::Double(f64::NEG_INFINITY));
+    round_trip("1.5f", &FrcValue::Float(1.5));
+    round_trip(
+        r#""tab\t\"quote\"""#,
+        &FrcValue::String(Box::from("tab\t\"quote\"")),
+    );
+    round_trip("hex\"00ff10\"", &FrcValue::Raw(Box::from([0, 255, 16])));
+    round_trip("[1, 2, 3]", &FrcValue::IntArray(Box::from([1, 2, 3])));
+    round_trip("[1.0f, 2.5f]", &FrcValue::FloatArray(Box::from([1.0, 2.5])));
+    round_trip(
+        "[true, false]",
+        &FrcValue::BooleanArray(Box::from([true, false])),
+    );
+    round_trip(
+        r#"["a", "b"]"#,
+        &FrcValue::StringArray(Box::from([Box::from("a"), Box::from("b")])),
+    );
+    round_trip("double[]", &FrcValue::DoubleArray(Box::default()));
+
+    assert_eq!("4d".parse(), Ok(FrcValue::Double(4.0)));
+    assert_eq!("4f".parse(), Ok(FrcValue::Float(4.0)));
+    assert!(matches!("nan".parse(), Ok(FrcValue::Double(v)) if v.is_nan()));
+    assert_eq!(
+        "[1, 2.5]".parse(),
+        Ok(FrcValue::DoubleArray(Box::from([1.0, 2.5])))
+    );
+    assert_eq!(
+        "float[1, 2]".parse(),
+        Ok(FrcValue::FloatArray(Box::from([1.0, 2.0])))
+    );
+    assert_eq!(
+        "b64\"AP8Q\"".parse(),
+        Ok(FrcValue::Raw(Box::from([0, 255, 16])))
+    );
+    assert_eq!("\"\\u{e9}\"".parse(), Ok(FrcValue::String(Box::from("é"))));
+
+    assert_eq!(
+        "[]".parse::<FrcValue>(),
+        Err(FrcValueParseError::UntypedEmptyArray)
+    );
+    assert_eq!(
+        "[1, true]".parse::<FrcValue>(),
+        Err(FrcValueParseError::MixedArray)
+    );
+    assert_eq!(
+        "1 2".parse::<FrcValue>(),
+        Err(FrcValueParseError::UnexpectedChar {
+            position: 2,
+            found: '2',
+            expected: "the end of the input"
+        })
+    );
+    assert_eq!(
+        "NotAStruct{}".parse::<FrcValue>(),
+        Err(FrcValueParseError::UnknownStruct("NotAStruct".to_owned()))
+    );
+}
+
+#[test]
+fn test_struct_literal() {
+    use crate as frclib_core;
+    use crate::structure::{FrcStructure, FrcStructureBytes};
+    use crate::value::FrcValueParseError;
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure, Default)]
+    #[repr(u8)]
+    enum LiteralMode {
+        #[default]
+        Idle,
+        Running,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure)]
+    struct LiteralTranslation {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure)]
+    struct LiteralRobot {
+        mode: LiteralMode,
+        enabled: bool,
+        position: LiteralTranslation,
+        speeds: [f32; 2],
+        id: u16,
+        name: [char; 4],
+    }
+
+    let robot = LiteralRobot {
+        mode: LiteralMode::Running,
+        enabled: true,
+        position: LiteralTranslation { x: 1.0, y: -2.5 },
+        speeds: [0.5, 1.0],
+        id: 254,
+        name: ['b', 'o', 't', '\0'],
+    };
+    let literal = "LiteralRobot{mode: LiteralMode{variant: Running}, enabled: true, \
+        position: LiteralTranslation{x: 1.0, y: -2.5}, speeds: [0.5f, 1.0f], id: 254, name: \"bot\"}";
+
+    let value = FrcValue::from_struct(&robot);
+    assert_eq!(value.literal().to_string(), literal);
+    assert_eq!(literal.parse::<FrcValue>().as_ref(), Ok(&value));
+
+    // field order is free, nested type names are optional and numbers are promoted
+    let shorthand = "LiteralRobot{name: \"bot\", id: 254, speeds: [0.5, 1], \
+        position: {y: -2.5, x: 1}, enabled: true, mode: {variant: 1}}";
+    assert_eq!(shorthand.parse::<FrcValue>().as_ref(), Ok(&value));
+
+    let translations = [
+        LiteralTranslation { x: 1.0, y: 2.0 },
+        LiteralTranslation { x: 3.0, y: 4.0 },
+    ];
+    let array = FrcValue::from_struct_array(&translations);
+    let literal = "[LiteralTranslation{x: 1.0, y: 2.0}, LiteralTranslation{x: 3.0, y: 4.0}]";
+    assert_eq!(array.literal().to_string(), literal);
+    assert_eq!(literal.parse::<FrcValue>().as_ref(), Ok(&array));
+    assert_eq!(
+        "LiteralTranslation[{x: 1, y: 2}, {x: 3, y: 4}]"
+            .parse::<FrcValue>()
+            .as_ref(),
+        Ok(&array)
+    );
+    assert_eq!(
+        "LiteralTranslation[]".parse::<FrcValue>(),
+        Ok(FrcValue::from_struct_array::<LiteralTranslation>(&[]))
+    );
+
+    let raw = "LiteralTranslation(hex\"000000000000f03f0000000000000040\")";
+    assert_eq!(
+        raw.parse::<FrcValue>().and_then(|v| v
+            .try_into_struct::<LiteralTranslation>()
+            .map_err(|_| FrcValueParseError::MixedArray)),
+        Ok(LiteralTranslation { x: 1.0, y: 2.0 })
+    );
+
+    assert_eq!(
+        "LiteralTranslation{x: 1.0}".parse::<FrcValue>(),
+        Err(FrcValueParseError::MissingField(
+            "LiteralTranslation".to_owned(),
+            "y".to_owned()
+        ))
+    );
+    assert_eq!(
+        "LiteralTranslation{x: 1.0, y: 2.0, z: 3.0}".parse::<FrcValue>(),
+        Err(FrcValueParseError::UnknownField(
+            "LiteralTranslation".to_owned(),
+            "z".to_owned()
+        ))
+    );
+    assert_eq!(
+        "LiteralMode{variant: Stopped}".parse::<FrcValue>(),
+        Err(FrcValueParseError::UnknownVariant(
+            "Stopped".to_owned(),
+            "variant".to_owned()
+        ))
+    );
+    assert_eq!(
+        "LiteralMode{variant: 256}".parse::<FrcValue>(),
+        Err(FrcValueParseError::OutOfRange("256".to_owned(), "uint8"))
+    );
+}
+
+#[test]
+fn test_struct_display() {
+    use crate as frclib_core;
+    use crate::structure::{FrcStructDesc, FrcStructure, FrcStructureBytes};
+
+    static UNKNOWN: FrcStructDesc = FrcStructDesc {
+        schema_supplier: || "NotRegistered inner".to_owned(),
+        type_str: "DisplayUnknown",
+        size: 2,
+    };
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure, Default)]
+    #[repr(u8)]
+    enum DisplayMode {
+        #[default]
+        Idle,
+        Running,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure)]
+    struct DisplayTranslation {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, FrcStructure)]
+    struct DisplayRobot {
+        mode: DisplayMode,
+        position: DisplayTranslation,
+        speeds: [f32; 2],
+        name: [char; 4],
+    }
+
+    let robot = DisplayRobot {
+        mode: DisplayMode::Running,
+        position: DisplayTranslation { x: 1.0, y: -2.5 },
+        speeds: [0.5, 1.0],
+        name: ['b', 'o', 't', '\0'],
+    };
+    assert_eq!(
+        FrcValue::from_struct(&robot).to_string(),
+        "DisplayRobot{mode: Running, position: DisplayTranslation{x: 1, y: -2.5}, \
+            speeds: [0.5, 1], name: \"bot\"}"
+    );
+    assert_eq!(
+        FrcValue::from_struct_array(&[robot.position, robot.position]).to_string(),
+        "[DisplayTranslation{x: 1, y: -2.5}, DisplayTranslation{x: 1, y: -2.5}]"
+    );
+
+    let unknown = FrcValue::Struct(Box::new(FrcStructureBytes::from_parts(
+        &UNKNOWN,
+        1,
+        Box::from([1, 2]),
+    )));
+    assert_eq!(unknown.to_string(), "Struct(DisplayUnknown):[1, 2]");
+}
+
+#[test]
+fn test_constraints() {
+    use crate::value::{FrcConstraintError, FrcConstraints, FrcType};
+
+    let voltage = FrcConstraints::new()
+        .of_type(FrcType::Double)
+        .range(0.0, 12.0)
+        .not_nan();
+    assert_eq!(voltage.validate(&FrcValue::Double(12.0)), Ok(()));
+    assert_eq!(
+        voltage.validate(&FrcValue::Double(12.5)),
+        Err(FrcConstraintError::OutOfRange {
+            value: 12.5,
+            min: 0.0,
+            max: 12.0
+        })
+    );
+    assert_eq!(
+        voltage.validate(&FrcValue::Int(6)),
+        Err(FrcConstraintError::WrongType {
+            expected: FrcType::Double,
+            found: FrcType::Int
+        })
+    );
+    assert!(voltage.validate(&FrcValue::Double(f64::NAN)).is_err());
+    assert!(voltage.validate(&FrcValue::Void).is_err());
+    assert_eq!(voltage.allow_void().validate(&FrcValue::Void), Ok(()));
+    assert_eq!(
+        FrcConstraints::new()
+            .not_nan()
+            .validate(&FrcValue::Float(f32::NAN)),
+        Err(FrcConstraintError::NaN)
+    );
+
+    let gains = FrcConstraints::new().exact_length(4).range(0.0, 1.0);
+    assert_eq!(
+        gains.validate(&FrcValue::DoubleArray(Box::from([0.1, 0.2, 0.3, 0.4]))),
+        Ok(())
+    );
+    assert_eq!(
+        gains.validate(&FrcValue::DoubleArray(Box::from([0.1, 0.2]))),
+        Err(FrcConstraintError::WrongLength {
+            len: 2,
+            min: 4,
+            max: 4
+        })
+    );
+    assert_eq!(
+        gains.validate(&FrcValue::DoubleArray(Box::from([0.1, 2.0, 0.3, 0.4]))),
+        Err(FrcConstraintError::Element {
+            index: 1,
+            source: Box::new(FrcConstraintError::OutOfRange {
+                value: 2.0,
+                min: 0.0,
+                max: 1.0
+            })
+        })
+    );
+    assert_eq!(
+        gains
+            .validate_all(&FrcValue::DoubleArray(Box::from([2.0])))
+            .len(),
+        2
+    );
+
+    let auto = FrcConstraints::new().one_of(["Left", "Right"]);
+    assert_eq!(auto.validate(&FrcValue::String(Box::from("Left"))), Ok(()));
+    assert_eq!(
+        auto.validate(&FrcValue::String(Box::from("Middle"))),
+        Err(FrcConstraintError::NotAllowed(Box::from("Middle")))
+    );
+    assert!(matches!(
+        auto.validate(&FrcValue::Int(0)),
+        Err(FrcConstraintError::NotApplicable {
+            constraint: "one of",
+            found: FrcType::Int
+        })
+    ));
+
+    let pose = FrcConstraints::new().struct_type("Pose2d");
+    assert!(matches!(
+        pose.validate(&FrcValue::Raw(Box::default())),
+        Err(FrcConstraintError::WrongStruct { .. })
+    ));
+}
+
+#[test]
+fn test_constrained_types() {
+    use crate::value::{
+        FrcConstrained, FrcConstraints, FrcKeyConstraints, FrcType, StaticallyFrcTyped,
+    };
+
+    struct Setpoint(f64);
+    impl From<Setpoint> for FrcValue {
+        fn from(value: Setpoint) -> Self {
+            Self::Double(value.0)
+        }
+    }
+    impl StaticallyFrcTyped for Setpoint {
+        const TYPE: FrcType = FrcType::Double;
+    }
+    impl FrcConstrained for Setpoint {
+        fn constraints() -> FrcConstraints {
+            FrcConstraints::new().range(-1.0, 1.0)
+        }
+    }
+    assert_eq!(Setpoint::validate(&Setpoint(0.5).into()), Ok(()));
+    assert!(Setpoint::validate(&FrcValue::Float(0.5)).is_err());
+
+    let voltage = FrcConstraints::new()
+        .of_type(FrcType::Double)
+        .range(0.0, 12.0);
+    let mut keys = FrcKeyConstraints::new();
+    assert!(keys.insert("/tuning/voltage", voltage).is_none());
+    assert!(keys.insert_typed::<Setpoint>("/tuning/setpoint").is_none());
+    assert_eq!(
+        keys.validate("/tuning/voltage", &FrcValue::Double(6.0)),
+        Ok(())
+    );
+    assert!(keys
+        .validate("/tuning/setpoint", &FrcValue::Double(2.0))
+        .is_err());
+    assert_eq!(keys.validate("/other", &FrcValue::Boolean(true)), Ok(()));
+}
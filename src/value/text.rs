@@ -0,0 +1,1026 @@
+//! A human readable literal syntax for [``FrcValue``] and a parser for it.
+//!
+//! | Variant | Literal |
+//! |---|---|
+//! | `Void` | `void` |
+//! | `Boolean` | `true`, `false` |
+//! | `Int` | `1`, `-20` |
+//! | `Double` | `1.0`, `2e-3`, `4d`, `nan`, `-inf` |
+//! | `Float` | `1.0f`, `4f`, `inff` |
+//! | `String` | `"hello\n"` |
+//! | `Raw` | `hex"0aff"`, `b64"Cv8="` |
+//! | `*Array` | `[1, 2, 3]`, `float[1, 2]`, `string[]` |
+//! | `Struct` | `Translation2d{x: 1.0, y: 2.0}`, `Translation2d(hex"...")` |
+//! | `StructArray` | `[Translation2d{..}, Translation2d{..}]`, `Translation2d[]` |
+//!
+//! Struct types are resolved through the [``FrcStructDescDB``] and their fields
+//! through the struct's schema, enum fields may be given by variant name.
+
+use std::{
+    fmt::{self, Display, Write},
+    str::FromStr,
+};
+
+use crate::structure::{
+    FrcStructDesc, FrcStructDescDB, FrcStructField, FrcStructFieldType, FrcStructPrimitive,
+    FrcStructSchema, FrcStructureBytes,
+};
+
+use super::{FrcValue, FrcValueParseError};
+
+type ParseResult<T> = Result<T, FrcValueParseError>;
+
+/// A [``Display``] adapter that writes an [``FrcValue``] in its literal form,
+/// created by [``FrcValue::literal``]
+#[derive(Debug, Clone, Copy)]
+pub struct FrcValueLiteral<'a>(&'a FrcValue);
+
+impl FrcValue {
+    /// Returns an adapter that displays the value in a form that can be parsed back
+    /// with [``str::parse``]
+    #[must_use]
+    pub const fn literal(&self) -> FrcValueLiteral<'_> {
+        FrcValueLiteral(self)
+    }
+}
+
+impl Display for FrcValueLiteral<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_value(f, self.0)
+    }
+}
+
+impl FromStr for FrcValue {
+    type Err = FrcValueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, pos: 0 };
+        let value = parser.value()?;
+        parser.skip_ws();
+        match parser.peek() {
+            None => Ok(value),
+            Some(_) => Err(parser.unexpected("the end of the input")),
+        }
+    }
+}
+
+// ---- formatting ----
+
+fn write_value(w: &mut impl Write, value: &FrcValue) -> fmt::Result {
+    match value {
+        FrcValue::Void => w.write_str("void"),
+        FrcValue::Boolean(v) => write!(w, "{v}"),
+        FrcValue::Int(v) => write!(w, "{v}"),
+        FrcValue::Double(v) => write_f64(w, *v),
+        FrcValue::Float(v) => write_f32(w, *v),
+        FrcValue::String(v) => write_quoted(w, v),
+        FrcValue::Raw(v) => write_hex(w, v),
+        FrcValue::BooleanArray(v) => write_array(w, "boolean", v, |w, v| write!(w, "{v}")),
+        FrcValue::IntArray(v) => write_array(w, "int", v, |w, v| write!(w, "{v}")),
+        FrcValue::FloatArray(v) => write_array(w, "float", v, |w, v| write_f32(w, *v)),
+        FrcValue::DoubleArray(v) => write_array(w, "double", v, |w, v| write_f64(w, *v)),
+        FrcValue::StringArray(v) => write_array(w, "string", v, |w, v| write_quoted(w, v)),
+        FrcValue::Struct(bytes) => {
+            let mut buffer = String::new();
+            if write_struct(&mut buffer, bytes.desc, &bytes.data, StructStyle::Literal).is_some() {
+                w.write_str(&buffer)
+            } else {
+                write!(w, "{}(", bytes.desc.type_str)?;
+                write_hex(w, &bytes.data)?;
+                w.write_char(')')
+            }
+        }
+        FrcValue::StructArray(bytes) => {
+            if bytes.count == 0 && bytes.data.is_empty() {
+                return write!(w, "{}[]", bytes.desc.type_str);
+            }
+            let mut buffer = String::new();
+            if write_struct_array(&mut buffer, bytes, StructStyle::Literal).is_some() {
+                w.write_str(&buffer)
+            } else {
+                write!(w, "{}[](", bytes.desc.type_str)?;
+                write_hex(w, &bytes.data)?;
+                w.write_char(')')
+            }
+        }
+    }
+}
+
+fn write_array<T>(
+    w: &mut impl Write,
+    type_name: &str,
+    values: &[T],
+    mut write_element: impl FnMut(&mut dyn Write, &T) -> fmt::Result,
+) -> fmt::Result {
+    if values.is_empty() {
+        return write!(w, "{type_name}[]");
+    }
+    w.write_char('[')?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            w.write_str(", ")?;
+        }
+        write_element(w, value)?;
+    }
+    w.write_char(']')
+}
+
+fn write_f64(w: &mut (impl Write + ?Sized), value: f64) -> fmt::Result {
+    if value.is_nan() {
+        w.write_str("nan")
+    } else {
+        write!(w, "{value:?}")
+    }
+}
+
+fn write_f32(w: &mut (impl Write + ?Sized), value: f32) -> fmt::Result {
+    if value.is_nan() {
+        w.write_str("nanf")
+    } else {
+        write!(w, "{value:?}f")
+    }
+}
+
+fn write_quoted(w: &mut (impl Write + ?Sized), value: &str) -> fmt::Result {
+    w.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            '\0' => w.write_str("\\0")?,
+            c if c.is_control() => write!(w, "\\u{{{:x}}}", u32::from(c))?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
+
+fn write_hex(w: &mut (impl Write + ?Sized), data: &[u8]) -> fmt::Result {
+    w.write_str("hex\"")?;
+    for byte in data {
+        write!(w, "{byte:02x}")?;
+    }
+    w.write_char('"')
+}
+
+/// Splits `n` bytes off the front of `data`
+fn take<'a>(data: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+    if data.len() < n {
+        return None;
+    }
+    let (head, tail) = data.split_at(n);
+    *data = tail;
+    Some(head)
+}
+
+/// How decoded struct fields are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum StructStyle {
+    /// The literal syntax that can be parsed back into the same bytes
+    Literal,
+    /// A more compact form for logs, floats drop their suffix
+    /// and enum types collapse to their variant name
+    Display,
+}
+
+/// Writes `Name{field: value, ...}` for a single struct,
+/// returns None if the struct could not be decoded with its schema
+pub(super) fn write_struct(
+    out: &mut String,
+    desc: &FrcStructDesc,
+    data: &[u8],
+    style: StructStyle,
+) -> Option<()> {
+    let schema = FrcStructSchema::from_desc(desc).ok()?;
+    if schema.size() != desc.size || data.len() != desc.size {
+        return None;
+    }
+    let mut data = data;
+    if let (StructStyle::Display, [field]) = (style, schema.fields.as_slice()) {
+        if !field.enum_variants.is_empty() && field.array_len.is_none() {
+            return write_field(out, field, &mut data, style);
+        }
+    }
+    out.push_str(desc.type_str);
+    out.push('{');
+    for (i, field) in schema.fields.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&field.name);
+        out.push_str(": ");
+        write_field(out, field, &mut data, style)?;
+    }
+    out.push('}');
+    Some(())
+}
+
+/// Writes `[Name{..}, Name{..}]` for a struct array,
+/// returns None if any struct could not be decoded with its schema
+pub(super) fn write_struct_array(
+    out: &mut String,
+    bytes: &FrcStructureBytes,
+    style: StructStyle,
+) -> Option<()> {
+    if bytes.desc.size == 0 || bytes.data.len() != bytes.desc.size * bytes.count {
+        return None;
+    }
+    out.push('[');
+    for (i, chunk) in bytes.data.chunks_exact(bytes.desc.size).enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_struct(out, bytes.desc, chunk, style)?;
+    }
+    out.push(']');
+    Some(())
+}
+
+fn write_field(
+    out: &mut String,
+    field: &FrcStructField,
+    data: &mut &[u8],
+    style: StructStyle,
+) -> Option<()> {
+    match (field.array_len, field.field_type) {
+        (Some(len), FrcStructFieldType::Primitive(FrcStructPrimitive::Char)) => {
+            let chars = take(data, len)?;
+            let end = chars.iter().position(|c| *c == 0).unwrap_or(len);
+            let string = String::from_utf8_lossy(chars.get(..end)?);
+            write_quoted(out, &string).ok()
+        }
+        (Some(len), field_type) => {
+            out.push('[');
+            for i in 0..len {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_element(out, field, field_type, data, style)?;
+            }
+            out.push(']');
+            Some(())
+        }
+        (None, field_type) => write_element(out, field, field_type, data, style),
+    }
+}
+
+fn write_element(
+    out: &mut String,
+    field: &FrcStructField,
+    field_type: FrcStructFieldType,
+    data: &mut &[u8],
+    style: StructStyle,
+) -> Option<()> {
+    macro_rules! read {
+        ($typ:ty) => {
+            <$typ>::from_le_bytes(take(data, std::mem::size_of::<$typ>())?.try_into().ok()?)
+        };
+    }
+    let prim = match field_type {
+        FrcStructFieldType::Struct(desc) => {
+            let nested = take(data, desc.size)?;
+            return write_struct(out, desc, nested, style);
+        }
+        FrcStructFieldType::Primitive(prim) => prim,
+    };
+    let int: i128 = match prim {
+        FrcStructPrimitive::Bool => {
+            out.push_str(if read!(u8) == 0 { "false" } else { "true" });
+            return Some(());
+        }
+        FrcStructPrimitive::Char => {
+            let c = read!(u8);
+            return write_quoted(out, &String::from_utf8_lossy(&[c])).ok();
+        }
+        FrcStructPrimitive::Float32 => {
+            let value = read!(f32);
+            return match style {
+                StructStyle::Literal => write_f32(out, value),
+                StructStyle::Display => write!(out, "{value}"),
+            }
+            .ok();
+        }
+        FrcStructPrimitive::Float64 => {
+            let value = read!(f64);
+            return match style {
+                StructStyle::Literal => write_f64(out, value),
+                StructStyle::Display => write!(out, "{value}"),
+            }
+            .ok();
+        }
+        FrcStructPrimitive::Int8 => read!(i8).into(),
+        FrcStructPrimitive::Int16 => read!(i16).into(),
+        FrcStructPrimitive::Int32 => read!(i32).into(),
+        FrcStructPrimitive::Int64 => read!(i64).into(),
+        FrcStructPrimitive::UInt8 => read!(u8).into(),
+        FrcStructPrimitive::UInt16 => read!(u16).into(),
+        FrcStructPrimitive::UInt32 => read!(u32).into(),
+        FrcStructPrimitive::UInt64 => read!(u64).into(),
+    };
+    match i64::try_from(int).ok().and_then(|i| field.variant_name(i)) {
+        Some(name) => out.push_str(name),
+        None => write!(out, "{int}").ok()?,
+    }
+    Some(())
+}
+
+// ---- parsing ----
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+const fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+const fn is_ident_continue(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            let _ = self.bump();
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> FrcValueParseError {
+        match self.peek() {
+            Some(found) => FrcValueParseError::UnexpectedChar {
+                position: self.pos,
+                found,
+                expected,
+            },
+            None => FrcValueParseError::UnexpectedEnd(expected),
+        }
+    }
+
+    /// Skips whitespace then consumes `c` if it is next
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            let _ = self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> ParseResult<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&mut pred) {
+            let _ = self.bump();
+        }
+        self.input.get(start..self.pos).unwrap_or_default()
+    }
+
+    fn ident(&mut self, expected: &'static str) -> ParseResult<&'a str> {
+        self.skip_ws();
+        if !self.peek().is_some_and(is_ident_start) {
+            return Err(self.unexpected(expected));
+        }
+        Ok(self.take_while(is_ident_continue))
+    }
+
+    /// Consumes a number like token, this includes `nan` and `inf`
+    fn number_token(&mut self) -> &'a str {
+        self.skip_ws();
+        let start = self.pos;
+        if matches!(self.peek(), Some('-' | '+')) {
+            let _ = self.bump();
+        }
+        let mut prev = None;
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_alphanumeric() || c == '.' => {}
+                Some('-' | '+') if matches!(prev, Some('e' | 'E')) => {}
+                _ => break,
+            }
+            prev = self.bump();
+        }
+        self.input.get(start..self.pos).unwrap_or_default()
+    }
+
+    fn value(&mut self) -> ParseResult<FrcValue> {
+        self.skip_ws();
+        match self.peek() {
+            Some('"') => Ok(FrcValue::String(self.string()?.into())),
+            Some('[') => self.untyped_array(),
+            Some(c) if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => {
+                number_value(self.number_token())
+            }
+            Some(c) if is_ident_start(c) => {
+                let start = self.pos;
+                match self.ident("a value")? {
+                    "void" => Ok(FrcValue::Void),
+                    "true" => Ok(FrcValue::Boolean(true)),
+                    "false" => Ok(FrcValue::Boolean(false)),
+                    "nan" | "inf" | "nanf" | "inff" => {
+                        self.pos = start;
+                        number_value(self.number_token())
+                    }
+                    encoding @ ("hex" | "b64") if self.peek() == Some('"') => {
+                        Ok(FrcValue::Raw(self.raw(encoding)?.into()))
+                    }
+                    type_name => self.typed(type_name),
+                }
+            }
+            _ => Err(self.unexpected("a value")),
+        }
+    }
+
+    fn string(&mut self) -> ParseResult<String> {
+        self.expect('"', "`\"`")?;
+        let mut string = String::new();
+        loop {
+            let escape_pos = self.pos;
+            match self.bump() {
+                None => return Err(FrcValueParseError::UnexpectedEnd("`\"`")),
+                Some('"') => return Ok(string),
+                Some('\\') => {
+                    let escaped = match self.bump() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('0') => '\0',
+                        Some('u') if self.eat('{') => {
+                            let digits = self.take_while(|c| c.is_ascii_hexdigit());
+                            u32::from_str_radix(digits, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .filter(|_| self.eat('}'))
+                                .ok_or(FrcValueParseError::InvalidEscape(escape_pos))?
+                        }
+                        _ => return Err(FrcValueParseError::InvalidEscape(escape_pos)),
+                    };
+                    string.push(escaped);
+                }
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn raw(&mut self, encoding: &str) -> ParseResult<Vec<u8>> {
+        self.expect('"', "`\"`")?;
+        let body = self.take_while(|c| c != '"');
+        self.expect('"', "`\"`")?;
+        match encoding {
+            "hex" => decode_hex(body).ok_or(FrcValueParseError::InvalidRaw("hex")),
+            "b64" => decode_base64(body).ok_or(FrcValueParseError::InvalidRaw("base64")),
+            _ => Err(FrcValueParseError::InvalidRaw("hex or base64")),
+        }
+    }
+
+    /// Parses a comma separated list ending in `]`, the opening `[` must already be consumed
+    fn list(
+        &mut self,
+        mut element: impl FnMut(&mut Self) -> ParseResult<()>,
+    ) -> ParseResult<usize> {
+        let mut count = 0;
+        loop {
+            if self.eat(']') {
+                return Ok(count);
+            }
+            element(self)?;
+            count += 1;
+            if !self.eat(',') {
+                self.expect(']', "`,` or `]`")?;
+                return Ok(count);
+            }
+        }
+    }
+
+    fn untyped_array(&mut self) -> ParseResult<FrcValue> {
+        self.expect('[', "`[`")?;
+        let mut values = Vec::new();
+        let _ = self.list(|parser| {
+            values.push(parser.value()?);
+            Ok(())
+        })?;
+        homogenize(values)
+    }
+
+    /// Parses a value that starts with a type name,
+    /// either a typed array or a struct
+    fn typed(&mut self, type_name: &str) -> ParseResult<FrcValue> {
+        self.skip_ws();
+        if self.peek() == Some('[') {
+            let element_type = match type_name {
+                "boolean" => Some(ArrayType::Boolean),
+                "int" => Some(ArrayType::Int),
+                "float" => Some(ArrayType::Float),
+                "double" => Some(ArrayType::Double),
+                "string" => Some(ArrayType::String),
+                _ => None,
+            };
+            if let Some(element_type) = element_type {
+                return self.typed_array(element_type);
+            }
+        }
+        let desc = FrcStructDescDB::get(type_name)
+            .ok_or_else(|| FrcValueParseError::UnknownStruct(type_name.to_owned()))?;
+        match self.peek() {
+            Some('{') => Ok(FrcValue::Struct(Box::new(FrcStructureBytes::from_parts(
+                desc,
+                1,
+                self.struct_body(desc)?.into(),
+            )))),
+            Some('(') => {
+                let _ = self.bump();
+                let encoding = self.ident("`hex` or `b64`")?;
+                let data = self.raw(encoding)?;
+                self.expect(')', "`)`")?;
+                check_struct_size(desc, desc.size, data.len())?;
+                Ok(FrcValue::Struct(Box::new(FrcStructureBytes::from_parts(
+                    desc,
+                    1,
+                    data.into(),
+                ))))
+            }
+            Some('[') => self.struct_array(desc),
+            _ => Err(self.unexpected("`{`, `(` or `[`")),
+        }
+    }
+
+    fn typed_array(&mut self, element_type: ArrayType) -> ParseResult<FrcValue> {
+        self.expect('[', "`[`")?;
+        let mut values = Vec::new();
+        let _ = self.list(|parser| {
+            values.push(parser.value()?);
+            Ok(())
+        })?;
+        let mismatch = || FrcValueParseError::MixedArray;
+        Ok(match element_type {
+            ArrayType::Boolean => FrcValue::BooleanArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        FrcValue::Boolean(b) => Ok(b),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<ParseResult<_>>()?,
+            ),
+            ArrayType::Int => FrcValue::IntArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        FrcValue::Int(i) => Ok(i),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<ParseResult<_>>()?,
+            ),
+            #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+            ArrayType::Float => FrcValue::FloatArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        FrcValue::Float(f) => Ok(f),
+                        FrcValue::Double(d) => Ok(d as f32),
+                        FrcValue::Int(i) => Ok(i as f32),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<ParseResult<_>>()?,
+            ),
+            #[allow(clippy::cast_precision_loss)]
+            ArrayType::Double => FrcValue::DoubleArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        FrcValue::Double(d) => Ok(d),
+                        FrcValue::Float(f) => Ok(f64::from(f)),
+                        FrcValue::Int(i) => Ok(i as f64),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<ParseResult<_>>()?,
+            ),
+            ArrayType::String => FrcValue::StringArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        FrcValue::String(s) => Ok(s),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<ParseResult<_>>()?,
+            ),
+        })
+    }
+
+    /// Parses the `[...]` after a struct type name
+    fn struct_array(&mut self, desc: &'static FrcStructDesc) -> ParseResult<FrcValue> {
+        self.expect('[', "`[`")?;
+        let mut data = Vec::new();
+        let mut count = self.list(|parser| parser.struct_element(desc, &mut data))?;
+        if count == 0 && self.eat('(') {
+            let encoding = self.ident("`hex` or `b64`")?;
+            data = self.raw(encoding)?;
+            self.expect(')', "`)`")?;
+            count = data.len().checked_div(desc.size).unwrap_or_default();
+            check_struct_size(desc, desc.size * count, data.len())?;
+        }
+        Ok(FrcValue::StructArray(Box::new(
+            FrcStructureBytes::from_parts(desc, count, data.into()),
+        )))
+    }
+
+    /// Parses a struct with an optional type name, `Name{...}` or `{...}`
+    fn struct_element(
+        &mut self,
+        desc: &'static FrcStructDesc,
+        out: &mut Vec<u8>,
+    ) -> ParseResult<()> {
+        self.skip_ws();
+        if self.peek().is_some_and(is_ident_start) {
+            let found = self.ident("a struct type")?;
+            if found != desc.type_str {
+                return Err(FrcValueParseError::MismatchedStruct {
+                    expected: desc.type_str.to_owned(),
+                    found: found.to_owned(),
+                });
+            }
+        }
+        out.extend(self.struct_body(desc)?);
+        Ok(())
+    }
+
+    /// Parses `{field: value, ...}` into the packed bytes of the struct
+    fn struct_body(&mut self, desc: &'static FrcStructDesc) -> ParseResult<Vec<u8>> {
+        let schema = FrcStructSchema::from_desc(desc)?;
+        self.expect('{', "`{`")?;
+        let mut encoded: Vec<Option<Vec<u8>>> = vec![None; schema.fields.len()];
+        loop {
+            if self.eat('}') {
+                break;
+            }
+            let name = self.ident("a field name")?;
+            let (index, field) = schema
+                .fields
+                .iter()
+                .enumerate()
+                .find(|(_, field)| field.name == name)
+                .ok_or_else(|| {
+                    FrcValueParseError::UnknownField(desc.type_str.to_owned(), name.to_owned())
+                })?;
+            self.expect(':', "`:`")?;
+            let mut bytes = Vec::with_capacity(field.size());
+            self.field(field, &mut bytes)?;
+            if let Some(slot) = encoded.get_mut(index) {
+                if slot.replace(bytes).is_some() {
+                    return Err(FrcValueParseError::DuplicateField(
+                        desc.type_str.to_owned(),
+                        name.to_owned(),
+                    ));
+                }
+            }
+            if !self.eat(',') {
+                self.expect('}', "`,` or `}`")?;
+                break;
+            }
+        }
+        let mut data = Vec::with_capacity(desc.size);
+        for (field, bytes) in schema.fields.iter().zip(encoded) {
+            let bytes = bytes.ok_or_else(|| {
+                FrcValueParseError::MissingField(desc.type_str.to_owned(), field.name.clone())
+            })?;
+            data.extend(bytes);
+        }
+        check_struct_size(desc, desc.size, data.len())?;
+        Ok(data)
+    }
+
+    fn field(&mut self, field: &FrcStructField, out: &mut Vec<u8>) -> ParseResult<()> {
+        match (field.array_len, field.field_type) {
+            (Some(len), FrcStructFieldType::Primitive(FrcStructPrimitive::Char)) => {
+                let string = self.string()?;
+                if string.len() > len {
+                    return Err(FrcValueParseError::ArrayLength(
+                        field.name.clone(),
+                        len,
+                        string.len(),
+                    ));
+                }
+                out.extend(string.bytes());
+                out.resize(out.len() + len - string.len(), 0);
+                Ok(())
+            }
+            (Some(len), field_type) => {
+                self.expect('[', "`[`")?;
+                let count = self.list(|parser| parser.element(field, field_type, out))?;
+                if count == len {
+                    Ok(())
+                } else {
+                    Err(FrcValueParseError::ArrayLength(
+                        field.name.clone(),
+                        len,
+                        count,
+                    ))
+                }
+            }
+            (None, field_type) => self.element(field, field_type, out),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn element(
+        &mut self,
+        field: &FrcStructField,
+        field_type: FrcStructFieldType,
+        out: &mut Vec<u8>,
+    ) -> ParseResult<()> {
+        let prim = match field_type {
+            FrcStructFieldType::Struct(desc) => return self.struct_element(desc, out),
+            FrcStructFieldType::Primitive(prim) => prim,
+        };
+        self.skip_ws();
+        match prim {
+            FrcStructPrimitive::Bool => match self.ident("`true` or `false`")? {
+                "true" => out.push(1),
+                "false" => out.push(0),
+                other => return Err(FrcValueParseError::OutOfRange(other.to_owned(), "bool")),
+            },
+            FrcStructPrimitive::Char => {
+                let string = self.string()?;
+                match string.as_bytes() {
+                    [c] => out.push(*c),
+                    _ => return Err(FrcValueParseError::OutOfRange(string, "char")),
+                }
+            }
+            FrcStructPrimitive::Float32 => {
+                out.extend((self.float()? as f32).to_le_bytes());
+            }
+            FrcStructPrimitive::Float64 => out.extend(self.float()?.to_le_bytes()),
+            int_prim => {
+                let token = if self.peek().is_some_and(is_ident_start) {
+                    self.ident("an integer")?
+                } else {
+                    self.number_token()
+                };
+                let value = match field.variant_value(token) {
+                    Some(value) => i128::from(value),
+                    None if token.starts_with(is_ident_start) => {
+                        return Err(FrcValueParseError::UnknownVariant(
+                            token.to_owned(),
+                            field.name.clone(),
+                        ))
+                    }
+                    None => token
+                        .parse::<i128>()
+                        .map_err(|_| FrcValueParseError::InvalidNumber(token.to_owned()))?,
+                };
+                write_int(int_prim, value, out).ok_or_else(|| {
+                    FrcValueParseError::OutOfRange(value.to_string(), int_name(int_prim))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn float(&mut self) -> ParseResult<f64> {
+        let token = self.number_token();
+        #[allow(clippy::cast_precision_loss)]
+        match number_value(token)? {
+            FrcValue::Double(d) => Ok(d),
+            FrcValue::Float(f) => Ok(f64::from(f)),
+            FrcValue::Int(i) => Ok(i as f64),
+            _ => Err(FrcValueParseError::InvalidNumber(token.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArrayType {
+    Boolean,
+    Int,
+    Float,
+    Double,
+    String,
+}
+
+fn check_struct_size(desc: &FrcStructDesc, expected: usize, found: usize) -> ParseResult<()> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(FrcValueParseError::StructSize(
+            desc.type_str.to_owned(),
+            expected,
+            found,
+        ))
+    }
+}
+
+/// Interprets a number token,
+/// `f` suffixed numbers are floats, numbers with a `.`, exponent or `d` suffix are doubles
+/// and everything else is an int
+fn number_value(token: &str) -> ParseResult<FrcValue> {
+    let invalid = || FrcValueParseError::InvalidNumber(token.to_owned());
+    let (negative, body) = match token.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    match (body, negative) {
+        ("nan", _) => return Ok(FrcValue::Double(f64::NAN)),
+        ("nanf", _) => return Ok(FrcValue::Float(f32::NAN)),
+        ("inf", false) => return Ok(FrcValue::Double(f64::INFINITY)),
+        ("inf", true) => return Ok(FrcValue::Double(f64::NEG_INFINITY)),
+        ("inff", false) => return Ok(FrcValue::Float(f32::INFINITY)),
+        ("inff", true) => return Ok(FrcValue::Float(f32::NEG_INFINITY)),
+        _ => {}
+    }
+    if !body.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return Err(invalid());
+    }
+    if let Some(float) = token.strip_suffix('f') {
+        float.parse().map(FrcValue::Float).map_err(|_| invalid())
+    } else if let Some(double) = token.strip_suffix('d') {
+        double.parse().map(FrcValue::Double).map_err(|_| invalid())
+    } else if body.contains(['.', 'e', 'E']) {
+        token.parse().map(FrcValue::Double).map_err(|_| invalid())
+    } else {
+        token.parse().map(FrcValue::Int).map_err(|_| invalid())
+    }
+}
+
+/// Combines the elements of an untyped array into a single array variant,
+/// ints are promoted to match doubles or floats
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn homogenize(values: Vec<FrcValue>) -> ParseResult<FrcValue> {
+    let Some(first) = values.first() else {
+        return Err(FrcValueParseError::UntypedEmptyArray);
+    };
+    let has = |pred: fn(&FrcValue) -> bool| values.iter().any(pred);
+    let all = |pred: fn(&FrcValue) -> bool| values.iter().all(pred);
+    let is_int = |v: &FrcValue| matches!(v, FrcValue::Int(_));
+
+    if all(|v| matches!(v, FrcValue::Boolean(_))) {
+        Ok(FrcValue::BooleanArray(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    FrcValue::Boolean(b) => Some(b),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    } else if all(|v| matches!(v, FrcValue::String(_))) {
+        Ok(FrcValue::StringArray(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    FrcValue::String(s) => Some(s),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    } else if all(is_int) {
+        Ok(FrcValue::IntArray(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    FrcValue::Int(i) => Some(i),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    } else if all(|v| matches!(v, FrcValue::Int(_) | FrcValue::Double(_))) {
+        Ok(FrcValue::DoubleArray(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    FrcValue::Double(d) => Some(d),
+                    FrcValue::Int(i) => Some(i as f64),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    } else if all(|v| matches!(v, FrcValue::Int(_) | FrcValue::Float(_))) {
+        Ok(FrcValue::FloatArray(
+            values
+                .into_iter()
+                .filter_map(|v| match v {
+                    FrcValue::Float(f) => Some(f),
+                    FrcValue::Int(i) => Some(i as f32),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    } else if let FrcValue::Struct(first) = first {
+        let desc = first.desc;
+        if has(|v| !matches!(v, FrcValue::Struct(_))) {
+            return Err(FrcValueParseError::MixedArray);
+        }
+        let mut data = Vec::with_capacity(desc.size * values.len());
+        for value in &values {
+            if let FrcValue::Struct(bytes) = value {
+                if bytes.desc.type_str != desc.type_str {
+                    return Err(FrcValueParseError::MixedArray);
+                }
+                data.extend_from_slice(&bytes.data);
+            }
+        }
+        Ok(FrcValue::StructArray(Box::new(
+            FrcStructureBytes::from_parts(desc, values.len(), data.into()),
+        )))
+    } else {
+        Err(FrcValueParseError::MixedArray)
+    }
+}
+
+fn write_int(prim: FrcStructPrimitive, value: i128, out: &mut Vec<u8>) -> Option<()> {
+    macro_rules! int {
+        ($typ:ty) => {
+            out.extend(<$typ>::try_from(value).ok()?.to_le_bytes())
+        };
+    }
+    match prim {
+        FrcStructPrimitive::Int8 => int!(i8),
+        FrcStructPrimitive::Int16 => int!(i16),
+        FrcStructPrimitive::Int32 => int!(i32),
+        FrcStructPrimitive::Int64 => int!(i64),
+        FrcStructPrimitive::UInt8 => int!(u8),
+        FrcStructPrimitive::UInt16 => int!(u16),
+        FrcStructPrimitive::UInt32 => int!(u32),
+        FrcStructPrimitive::UInt64 => int!(u64),
+        _ => return None,
+    }
+    Some(())
+}
+
+const fn int_name(prim: FrcStructPrimitive) -> &'static str {
+    match prim {
+        FrcStructPrimitive::Int8 => "int8",
+        FrcStructPrimitive::Int16 => "int16",
+        FrcStructPrimitive::Int32 => "int32",
+        FrcStructPrimitive::Int64 => "int64",
+        FrcStructPrimitive::UInt8 => "uint8",
+        FrcStructPrimitive::UInt16 => "uint16",
+        FrcStructPrimitive::UInt32 => "uint32",
+        FrcStructPrimitive::UInt64 => "uint64",
+        _ => "integer",
+    }
+}
+
+fn decode_hex(body: &str) -> Option<Vec<u8>> {
+    if body.len() % 2 != 0 {
+        return None;
+    }
+    (0..body.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(body.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn decode_base64(body: &str) -> Option<Vec<u8>> {
+    fn sextet(c: u8) -> Option<u32> {
+        match c {
+            b'A'..=b'Z' => Some(u32::from(c - b'A')),
+            b'a'..=b'z' => Some(u32::from(c - b'a') + 26),
+            b'0'..=b'9' => Some(u32::from(c - b'0') + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+    let body = body.trim_end_matches('=').as_bytes();
+    if body.len() % 4 == 1 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(body.len() * 3 / 4);
+    for chunk in body.chunks(4) {
+        let mut bits = 0u32;
+        for c in chunk {
+            bits = (bits << 6) | sextet(*c)?;
+        }
+        bits <<= 6 * (4 - chunk.len());
+        let bytes = bits.to_be_bytes();
+        out.extend(bytes.get(1..chunk.len())?);
+    }
+    Some(out)
+}